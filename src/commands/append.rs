@@ -0,0 +1,48 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct AppendArguments {
+    key: String,
+    value: String,
+}
+
+impl AppendArguments {
+    pub fn new<K, V>(key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        Self {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for AppendArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.value.clone()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = AppendArguments::new("foo", "bar").to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("bar".into()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+#[derive(Clone)]
+pub struct AuthArguments {
+    username: Option<String>,
+    password: String,
+}
+
+impl AuthArguments {
+    pub fn new<P: ToString>(username: Option<&str>, password: P) -> Self {
+        Self {
+            username: username.map(|username| username.to_string()),
+            password: password.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for AuthArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut arguments = Vec::new();
+
+        if let Some(username) = &self.username {
+            arguments.push(ProtocolDataType::BulkString(username.clone()));
+        }
+
+        arguments.push(ProtocolDataType::BulkString(self.password.clone()));
+
+        arguments
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_without_username() {
+        let result = AuthArguments::new(None, "secret").to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("secret".into())]);
+    }
+
+    #[test]
+    fn builds_with_username() {
+        let result = AuthArguments::new(Some("john"), "secret").to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("john".into()),
+                ProtocolDataType::BulkString("secret".into()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct SetBitArguments {
+    key: String,
+    offset: u64,
+    value: bool,
+}
+
+impl SetBitArguments {
+    pub fn new<K: ToString>(key: K, offset: u64, value: bool) -> Self {
+        Self {
+            key: key.to_string(),
+            offset,
+            value,
+        }
+    }
+}
+
+impl CommandArguments for SetBitArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.offset.to_string()),
+            ProtocolDataType::BulkString(String::from(if self.value { "1" } else { "0" })),
+        ]
+    }
+}
+
+pub struct GetBitArguments {
+    key: String,
+    offset: u64,
+}
+
+impl GetBitArguments {
+    pub fn new<K: ToString>(key: K, offset: u64) -> Self {
+        Self {
+            key: key.to_string(),
+            offset,
+        }
+    }
+}
+
+impl CommandArguments for GetBitArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.offset.to_string()),
+        ]
+    }
+}
+
+pub struct BitCountArguments {
+    key: String,
+    /// Inclusive byte range to count the bits in
+    range: Option<(i64, i64)>,
+}
+
+impl BitCountArguments {
+    pub fn new<K: ToString>(key: K, range: Option<(i64, i64)>) -> Self {
+        Self {
+            key: key.to_string(),
+            range,
+        }
+    }
+}
+
+impl CommandArguments for BitCountArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut args = vec![ProtocolDataType::BulkString(self.key.clone())];
+
+        if let Some((start, end)) = self.range {
+            args.push(ProtocolDataType::BulkString(start.to_string()));
+            args.push(ProtocolDataType::BulkString(end.to_string()));
+        }
+
+        args
+    }
+}
+
+pub struct BitPosArguments {
+    key: String,
+    bit: bool,
+    /// Inclusive byte range to look for the bit in
+    range: Option<(i64, i64)>,
+}
+
+impl BitPosArguments {
+    pub fn new<K: ToString>(key: K, bit: bool, range: Option<(i64, i64)>) -> Self {
+        Self {
+            key: key.to_string(),
+            bit,
+            range,
+        }
+    }
+}
+
+impl CommandArguments for BitPosArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut args = vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(u8::from(self.bit).to_string()),
+        ];
+
+        if let Some((start, end)) = self.range {
+            args.push(ProtocolDataType::BulkString(start.to_string()));
+            args.push(ProtocolDataType::BulkString(end.to_string()));
+        }
+
+        args
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_setbit_correctly() {
+        let result = SetBitArguments::new("foo", 7, true).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("7".into()),
+                ProtocolDataType::BulkString("1".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_getbit_correctly() {
+        let result = GetBitArguments::new("foo", 7).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("7".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_bitcount_without_range() {
+        let result = BitCountArguments::new("foo", None).to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("foo".into())]);
+    }
+
+    #[test]
+    fn builds_bitcount_with_range() {
+        let result = BitCountArguments::new("foo", Some((0, -1))).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("0".into()),
+                ProtocolDataType::BulkString("-1".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_bitpos_without_range() {
+        let result = BitPosArguments::new("foo", false, None).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("0".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_bitpos_with_range() {
+        let result = BitPosArguments::new("foo", true, Some((2, -1))).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("1".into()),
+                ProtocolDataType::BulkString("2".into()),
+                ProtocolDataType::BulkString("-1".into()),
+            ]
+        );
+    }
+}
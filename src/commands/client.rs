@@ -0,0 +1,102 @@
+use crate::{error::CamasError, protocol::ProtocolDataType};
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct ClientSetNameArguments {
+    name: String,
+}
+
+impl ClientSetNameArguments {
+    pub fn new<N: ToString>(name: N) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+
+    /// Checks that the name has no whitespace, which Redis rejects
+    pub(crate) fn validate(&self) -> Result<(), CamasError> {
+        if self.name.chars().any(|c| c.is_ascii_whitespace()) {
+            return Err(CamasError::InvalidArgument(format!(
+                "Client names can't contain spaces or newlines, got {:?}",
+                self.name
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl CommandArguments for ClientSetNameArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString("SETNAME".into()),
+            ProtocolDataType::BulkString(self.name.clone()),
+        ]
+    }
+}
+
+#[derive(Default)]
+pub struct ClientGetNameArguments;
+
+impl ClientGetNameArguments {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CommandArguments for ClientGetNameArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![ProtocolDataType::BulkString("GETNAME".into())]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use crate::commands::Command;
+
+    use super::*;
+
+    #[test]
+    fn serializes_client_setname_correctly() {
+        let command = Command::ClientSetName(ClientSetNameArguments::new("worker"));
+
+        assert_eq!(
+            command.serialize(),
+            b"*3\r\n$6\r\nCLIENT\r\n$7\r\nSETNAME\r\n$6\r\nworker\r\n"
+        );
+    }
+
+    #[test]
+    fn serializes_client_getname_correctly() {
+        let command = Command::ClientGetName(ClientGetNameArguments::new());
+
+        assert_eq!(
+            command.serialize(),
+            b"*2\r\n$6\r\nCLIENT\r\n$7\r\nGETNAME\r\n"
+        );
+    }
+}
+
+#[cfg(test)]
+mod validation {
+    use super::*;
+
+    #[test]
+    fn accepts_name_without_whitespace() {
+        assert!(ClientSetNameArguments::new("worker-1").validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_name_with_space() {
+        let result = ClientSetNameArguments::new("worker 1").validate();
+
+        assert!(matches!(result, Err(CamasError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn rejects_name_with_newline() {
+        let result = ClientSetNameArguments::new("worker\n1").validate();
+
+        assert!(matches!(result, Err(CamasError::InvalidArgument(_))));
+    }
+}
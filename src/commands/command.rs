@@ -0,0 +1,35 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+#[derive(Default)]
+pub struct CommandCountArguments;
+
+impl CommandCountArguments {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CommandArguments for CommandCountArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![ProtocolDataType::BulkString("COUNT".into())]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use crate::commands::Command;
+
+    use super::*;
+
+    #[test]
+    fn serializes_command_count_correctly() {
+        let command = Command::CommandCount(CommandCountArguments::new());
+
+        assert_eq!(
+            command.serialize(),
+            b"*2\r\n$7\r\nCOMMAND\r\n$5\r\nCOUNT\r\n"
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{error::CamasError, from_redis_value::FromRedisValue, protocol::ProtocolDataType};
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct ConfigGetArguments {
+    parameter: String,
+}
+
+impl ConfigGetArguments {
+    pub fn new<P: ToString>(parameter: P) -> Self {
+        Self {
+            parameter: parameter.to_string(),
+        }
+    }
+
+    /// Converts the reply of `CONFIG GET` into parameter/value pairs.
+    ///
+    /// Under RESP2, parameters and values are interleaved in a flat array,
+    /// while under RESP3 they come in a map.
+    pub(crate) fn parse_response(
+        response: &ProtocolDataType,
+    ) -> Result<Vec<(String, String)>, CamasError> {
+        let parse_pair = |parameter, value| -> Result<_, CamasError> {
+            Ok((
+                String::from_redis_value(parameter)?,
+                String::from_redis_value(value)?,
+            ))
+        };
+
+        match response {
+            ProtocolDataType::Map(pairs) => pairs
+                .iter()
+                .map(|(parameter, value)| parse_pair(parameter, value))
+                .collect(),
+            ProtocolDataType::Array(items) => items
+                .chunks(2)
+                .map(|pair| match pair {
+                    [parameter, value] => parse_pair(parameter, value),
+                    _ => Err(CamasError::UnexpectedReply(response.clone())),
+                })
+                .collect(),
+            _ => Err(CamasError::UnexpectedReply(response.clone())),
+        }
+    }
+}
+
+impl CommandArguments for ConfigGetArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString("GET".into()),
+            ProtocolDataType::BulkString(self.parameter.clone()),
+        ]
+    }
+}
+
+pub struct ConfigSetArguments {
+    parameter: String,
+    value: String,
+}
+
+impl ConfigSetArguments {
+    pub fn new<P, V>(parameter: P, value: V) -> Self
+    where
+        P: ToString,
+        V: ToString,
+    {
+        Self {
+            parameter: parameter.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for ConfigSetArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString("SET".into()),
+            ProtocolDataType::BulkString(self.parameter.clone()),
+            ProtocolDataType::BulkString(self.value.clone()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use crate::commands::Command;
+
+    use super::*;
+
+    #[test]
+    fn builds_config_get_correctly() {
+        let result = ConfigGetArguments::new("maxmemory").to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("GET".into()),
+                ProtocolDataType::BulkString("maxmemory".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn serializes_config_set_correctly() {
+        let command = Command::ConfigSet(ConfigSetArguments::new("maxmemory-policy", "noeviction"));
+
+        assert_eq!(
+            command.serialize(),
+            b"*4\r\n$6\r\nCONFIG\r\n$3\r\nSET\r\n$16\r\nmaxmemory-policy\r\n$10\r\nnoeviction\r\n"
+        );
+    }
+}
+
+#[cfg(test)]
+mod response {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn parses_interleaved_parameters_and_values() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("maxmemory".into()),
+            ProtocolDataType::BulkString("0".into()),
+            ProtocolDataType::BulkString("maxmemory-policy".into()),
+            ProtocolDataType::BulkString("noeviction".into()),
+        ]);
+
+        let result = ConfigGetArguments::parse_response(&response)?;
+
+        assert_eq!(
+            result,
+            vec![
+                ("maxmemory".into(), "0".into()),
+                ("maxmemory-policy".into(), "noeviction".into()),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_map_of_parameters_and_values() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Map(vec![(
+            ProtocolDataType::BulkString("maxmemory".into()),
+            ProtocolDataType::BulkString("0".into()),
+        )]);
+
+        let result = ConfigGetArguments::parse_response(&response)?;
+
+        assert_eq!(result, vec![("maxmemory".into(), "0".into())]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_parameter_without_value() {
+        let response =
+            ProtocolDataType::Array(vec![ProtocolDataType::BulkString("maxmemory".into())]);
+
+        let result = ConfigGetArguments::parse_response(&response);
+
+        assert!(matches!(result, Err(CamasError::UnexpectedReply(_))));
+    }
+
+    #[test]
+    fn fails_on_non_map_or_array_reply() {
+        let result = ConfigGetArguments::parse_response(&ProtocolDataType::Integer(1));
+
+        assert!(matches!(result, Err(CamasError::UnexpectedReply(_))));
+    }
+}
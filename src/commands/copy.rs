@@ -0,0 +1,89 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct CopyArguments {
+    source: String,
+    destination: String,
+    replace: bool,
+    db: Option<u32>,
+}
+
+impl CopyArguments {
+    pub fn new<K: ToString>(source: K, destination: K, replace: bool, db: Option<u32>) -> Self {
+        Self {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            replace,
+            db,
+        }
+    }
+}
+
+impl CommandArguments for CopyArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut args = vec![
+            ProtocolDataType::BulkString(self.source.clone()),
+            ProtocolDataType::BulkString(self.destination.clone()),
+        ];
+
+        if let Some(db) = self.db {
+            args.push(ProtocolDataType::BulkString("DB".into()));
+            args.push(ProtocolDataType::BulkString(db.to_string()));
+        }
+
+        if self.replace {
+            args.push(ProtocolDataType::BulkString("REPLACE".into()));
+        }
+
+        args
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = CopyArguments::new("foo", "bar", false, None).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("bar".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_correctly_with_replace() {
+        let result = CopyArguments::new("foo", "bar", true, None).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("bar".into()),
+                ProtocolDataType::BulkString("REPLACE".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_correctly_with_db_and_replace() {
+        let result = CopyArguments::new("foo", "bar", true, Some(2)).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("bar".into()),
+                ProtocolDataType::BulkString("DB".into()),
+                ProtocolDataType::BulkString("2".into()),
+                ProtocolDataType::BulkString("REPLACE".into()),
+            ]
+        );
+    }
+}
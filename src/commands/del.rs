@@ -2,7 +2,7 @@ use crate::protocol::ProtocolDataType;
 
 use super::{CommandArguments, ProtocolCommandArguments};
 
-pub(crate) struct DelArguments {
+pub struct DelArguments {
     keys: Vec<String>,
 }
 
@@ -0,0 +1,146 @@
+use crate::{error::CamasError, protocol::ProtocolDataType};
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct DumpArguments {
+    key: String,
+}
+
+impl DumpArguments {
+    pub fn new<K: ToString>(key: K) -> Self {
+        Self {
+            key: key.to_string(),
+        }
+    }
+
+    /// Converts the reply of `DUMP` into the serialized value, which is
+    /// `None` if the key is not set.
+    ///
+    /// The serialization format is binary, but may happen to be valid UTF-8,
+    /// so it can be parsed into either kind of bulk string.
+    pub(crate) fn parse_response(
+        response: ProtocolDataType,
+    ) -> Result<Option<Vec<u8>>, CamasError> {
+        match response {
+            ProtocolDataType::Null => Ok(None),
+            ProtocolDataType::BulkBytes(bytes) => Ok(Some(bytes)),
+            ProtocolDataType::BulkString(string) => Ok(Some(string.into_bytes())),
+            response => Err(CamasError::UnexpectedReply(response)),
+        }
+    }
+}
+
+impl CommandArguments for DumpArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![ProtocolDataType::BulkString(self.key.clone())]
+    }
+}
+
+pub struct RestoreArguments {
+    key: String,
+    /// In milliseconds, where 0 means the key doesn't expire
+    ttl: u64,
+    serialized: Vec<u8>,
+    replace: bool,
+}
+
+impl RestoreArguments {
+    pub fn new<K: ToString>(key: K, ttl: u64, serialized: &[u8], replace: bool) -> Self {
+        Self {
+            key: key.to_string(),
+            ttl,
+            serialized: serialized.to_vec(),
+            replace,
+        }
+    }
+}
+
+impl CommandArguments for RestoreArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut arguments = vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.ttl.to_string()),
+            ProtocolDataType::BulkBytes(self.serialized.clone()),
+        ];
+
+        if self.replace {
+            arguments.push(ProtocolDataType::BulkString("REPLACE".into()));
+        }
+
+        arguments
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_dump_correctly() {
+        let result = DumpArguments::new("foo").to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("foo".into())]);
+    }
+
+    #[test]
+    fn builds_restore_correctly() {
+        let result = RestoreArguments::new("foo", 0, &[0x00, 0xFF], false).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("0".into()),
+                ProtocolDataType::BulkBytes(vec![0x00, 0xFF]),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_restore_with_replace() {
+        let result =
+            RestoreArguments::new("foo", 1000, &[0x00, 0xFF], true).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("1000".into()),
+                ProtocolDataType::BulkBytes(vec![0x00, 0xFF]),
+                ProtocolDataType::BulkString("REPLACE".into()),
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod response {
+    use super::*;
+
+    #[test]
+    fn parses_binary_value() -> Result<(), CamasError> {
+        let response = ProtocolDataType::BulkBytes(vec![0x00, 0xFF]);
+
+        assert_eq!(
+            DumpArguments::parse_response(response)?,
+            Some(vec![0x00, 0xFF])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_null_into_none() -> Result<(), CamasError> {
+        assert_eq!(DumpArguments::parse_response(ProtocolDataType::Null)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_on_unexpected_reply() {
+        assert!(matches!(
+            DumpArguments::parse_response(ProtocolDataType::Integer(1)),
+            Err(CamasError::UnexpectedReply(_))
+        ));
+    }
+}
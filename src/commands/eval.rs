@@ -0,0 +1,109 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct EvalArguments {
+    /// The script itself, or its SHA1 hash for `EVALSHA`
+    script: String,
+    keys: Vec<String>,
+    args: Vec<String>,
+}
+
+impl EvalArguments {
+    pub fn new<S: ToString>(script: S, keys: &[&str], args: &[&str]) -> Self {
+        Self {
+            script: script.to_string(),
+            keys: keys.iter().map(|key| key.to_string()).collect(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        }
+    }
+}
+
+impl CommandArguments for EvalArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut arguments = vec![
+            ProtocolDataType::BulkString(self.script.clone()),
+            ProtocolDataType::BulkString(self.keys.len().to_string()),
+        ];
+
+        arguments.extend(self.keys.iter().cloned().map(ProtocolDataType::BulkString));
+        arguments.extend(self.args.iter().cloned().map(ProtocolDataType::BulkString));
+
+        arguments
+    }
+}
+
+pub struct ScriptLoadArguments {
+    script: String,
+}
+
+impl ScriptLoadArguments {
+    pub fn new<S: ToString>(script: S) -> Self {
+        Self {
+            script: script.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for ScriptLoadArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString("LOAD".into()),
+            ProtocolDataType::BulkString(self.script.clone()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = EvalArguments::new(
+            "return redis.call('mset', KEYS[1], ARGV[1], KEYS[2], ARGV[1])",
+            &["foo", "bar"],
+            &["baz"],
+        )
+        .to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString(
+                    "return redis.call('mset', KEYS[1], ARGV[1], KEYS[2], ARGV[1])".into()
+                ),
+                ProtocolDataType::BulkString("2".into()),
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("bar".into()),
+                ProtocolDataType::BulkString("baz".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_without_keys_nor_arguments() {
+        let result = EvalArguments::new("return 1", &[], &[]).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("return 1".into()),
+                ProtocolDataType::BulkString("0".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_script_load_correctly() {
+        let result = ScriptLoadArguments::new("return 1").to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("LOAD".into()),
+                ProtocolDataType::BulkString("return 1".into()),
+            ]
+        );
+    }
+}
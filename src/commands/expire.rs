@@ -0,0 +1,148 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+/// A condition that must hold for an expiration time to be applied
+#[derive(Clone, Copy)]
+pub enum ExpireCondition {
+    Nx,
+    Xx,
+    Gt,
+    Lt,
+}
+
+pub struct ExpireArguments {
+    key: String,
+    /// A duration or a Unix timestamp, depending on the command
+    time: i64,
+    condition: Option<ExpireCondition>,
+}
+
+impl ExpireArguments {
+    pub fn new<K: ToString>(key: K, time: i64, condition: Option<ExpireCondition>) -> Self {
+        Self {
+            key: key.to_string(),
+            time,
+            condition,
+        }
+    }
+}
+
+impl CommandArguments for ExpireArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut arguments = vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.time.to_string()),
+        ];
+
+        if let Some(condition) = &self.condition {
+            match condition {
+                ExpireCondition::Nx => {
+                    arguments.push(ProtocolDataType::BulkString("NX".into()));
+                }
+                ExpireCondition::Xx => {
+                    arguments.push(ProtocolDataType::BulkString("XX".into()));
+                }
+                ExpireCondition::Gt => {
+                    arguments.push(ProtocolDataType::BulkString("GT".into()));
+                }
+                ExpireCondition::Lt => {
+                    arguments.push(ProtocolDataType::BulkString("LT".into()));
+                }
+            }
+        }
+
+        arguments
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_without_condition() {
+        let result = ExpireArguments::new("foo", 42, None).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("42".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_with_nx_condition() {
+        let result =
+            ExpireArguments::new("foo", 42, Some(ExpireCondition::Nx)).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("42".into()),
+                ProtocolDataType::BulkString("NX".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_with_xx_condition() {
+        let result =
+            ExpireArguments::new("foo", 42, Some(ExpireCondition::Xx)).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("42".into()),
+                ProtocolDataType::BulkString("XX".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_with_gt_condition() {
+        let result =
+            ExpireArguments::new("foo", 42, Some(ExpireCondition::Gt)).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("42".into()),
+                ProtocolDataType::BulkString("GT".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_with_lt_condition() {
+        let result =
+            ExpireArguments::new("foo", 42, Some(ExpireCondition::Lt)).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("42".into()),
+                ProtocolDataType::BulkString("LT".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_with_negative_seconds() {
+        let result = ExpireArguments::new("foo", -1, None).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("-1".into()),
+            ]
+        );
+    }
+}
@@ -2,7 +2,7 @@ use crate::protocol::ProtocolDataType;
 
 use super::{CommandArguments, ProtocolCommandArguments};
 
-pub(crate) struct FlushDbArguments {
+pub struct FlushDbArguments {
     async_flush: bool,
 }
 
@@ -2,7 +2,7 @@ use crate::protocol::ProtocolDataType;
 
 use super::{CommandArguments, ProtocolCommandArguments};
 
-pub(crate) struct GetArguments {
+pub struct GetArguments {
     key: String,
 }
 
@@ -0,0 +1,141 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{set::ExpirationTime, CommandArguments, ProtocolCommandArguments};
+
+pub struct GetExArguments {
+    key: String,
+    expiration_time: Option<ExpirationTime>,
+    persist: bool,
+}
+
+impl GetExArguments {
+    pub fn new<K: ToString>(key: K, expiration_time: Option<ExpirationTime>) -> Self {
+        Self {
+            key: key.to_string(),
+            expiration_time,
+            persist: false,
+        }
+    }
+
+    pub fn persist<K: ToString>(key: K) -> Self {
+        Self {
+            key: key.to_string(),
+            expiration_time: None,
+            persist: true,
+        }
+    }
+}
+
+impl CommandArguments for GetExArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut arguments = vec![ProtocolDataType::BulkString(self.key.clone())];
+
+        if self.persist {
+            arguments.push(ProtocolDataType::BulkString("PERSIST".into()));
+        } else if let Some(expiration_time) = self.expiration_time {
+            // GETEX keeps the current time to live unless told otherwise, so
+            // there's no KEEPTTL token to send
+            if !matches!(expiration_time, ExpirationTime::KeepTTL) {
+                arguments.extend(expiration_time.to_protocol_arguments());
+            }
+        }
+
+        arguments
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_without_expiration_time() {
+        let result = GetExArguments::new("foo", None).to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("foo".into())]);
+    }
+
+    #[test]
+    fn builds_with_seconds_expiration_time() {
+        let result =
+            GetExArguments::new("foo", Some(ExpirationTime::Seconds(42))).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("EX".into()),
+                ProtocolDataType::BulkString("42".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_with_milliseconds_expiration_time() {
+        let result = GetExArguments::new("foo", Some(ExpirationTime::Milliseconds(42000)))
+            .to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("PX".into()),
+                ProtocolDataType::BulkString("42000".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_with_timestamp_seconds_expiration_time() {
+        let result = GetExArguments::new("foo", Some(ExpirationTime::TimestampSeconds(1712451584)))
+            .to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("EXAT".into()),
+                ProtocolDataType::BulkString("1712451584".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_with_timestamp_milliseconds_expiration_time() {
+        let result = GetExArguments::new(
+            "foo",
+            Some(ExpirationTime::TimestampMilliseconds(1712451584000)),
+        )
+        .to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("PXAT".into()),
+                ProtocolDataType::BulkString("1712451584000".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_without_token_when_keeping_ttl() {
+        let result =
+            GetExArguments::new("foo", Some(ExpirationTime::KeepTTL)).to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("foo".into())]);
+    }
+
+    #[test]
+    fn builds_with_persist() {
+        let result = GetExArguments::persist("foo").to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("PERSIST".into()),
+            ]
+        );
+    }
+}
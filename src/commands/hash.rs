@@ -0,0 +1,200 @@
+use crate::{data_type::DataType, error::CamasError, protocol::ProtocolDataType};
+
+use super::{mget::MGetArguments, CommandArguments, ProtocolCommandArguments};
+
+pub struct HSetArguments {
+    key: String,
+    field: String,
+    value: String,
+}
+
+impl HSetArguments {
+    pub fn new<K, F, V>(key: K, field: F, value: V) -> Self
+    where
+        K: ToString,
+        F: ToString,
+        V: ToString,
+    {
+        Self {
+            key: key.to_string(),
+            field: field.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for HSetArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.field.clone()),
+            ProtocolDataType::BulkString(self.value.clone()),
+        ]
+    }
+}
+
+pub struct HGetArguments {
+    key: String,
+    field: String,
+}
+
+impl HGetArguments {
+    pub fn new<K, F>(key: K, field: F) -> Self
+    where
+        K: ToString,
+        F: ToString,
+    {
+        Self {
+            key: key.to_string(),
+            field: field.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for HGetArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.field.clone()),
+        ]
+    }
+}
+
+pub struct HMGetArguments {
+    key: String,
+    fields: Vec<String>,
+}
+
+impl HMGetArguments {
+    pub fn new<K, F>(key: K, fields: &[F]) -> Self
+    where
+        K: ToString,
+        F: ToString,
+    {
+        Self {
+            key: key.to_string(),
+            fields: fields.iter().map(|field| field.to_string()).collect(),
+        }
+    }
+
+    /// Converts the array returned by `HMGET` into values ordered like the
+    /// requested fields, which is shaped just like the one `MGET` returns.
+    pub(crate) fn parse_response(
+        response: &ProtocolDataType,
+    ) -> Result<Vec<Option<DataType>>, CamasError> {
+        MGetArguments::parse_response(response)
+    }
+}
+
+impl CommandArguments for HMGetArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut arguments = vec![ProtocolDataType::BulkString(self.key.clone())];
+
+        arguments.extend(
+            self.fields
+                .iter()
+                .cloned()
+                .map(ProtocolDataType::BulkString),
+        );
+
+        arguments
+    }
+}
+
+pub struct HKeysArguments {
+    key: String,
+}
+
+impl HKeysArguments {
+    pub fn new<K: ToString>(key: K) -> Self {
+        Self {
+            key: key.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for HKeysArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![ProtocolDataType::BulkString(self.key.clone())]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_hset_correctly() {
+        let result = HSetArguments::new("user", "name", "John").to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("user".into()),
+                ProtocolDataType::BulkString("name".into()),
+                ProtocolDataType::BulkString("John".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_hget_correctly() {
+        let result = HGetArguments::new("user", "name").to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("user".into()),
+                ProtocolDataType::BulkString("name".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_hmget_correctly() {
+        let result = HMGetArguments::new("user", &["name", "age"]).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("user".into()),
+                ProtocolDataType::BulkString("name".into()),
+                ProtocolDataType::BulkString("age".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_hkeys_correctly() {
+        let result = HKeysArguments::new("user").to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("user".into())]);
+    }
+}
+
+#[cfg(test)]
+mod response {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn parses_hmget_values_keeping_binary_ones() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("John".into()),
+            ProtocolDataType::Null,
+            ProtocolDataType::BulkBytes(vec![0xff]),
+        ]);
+
+        assert_eq!(
+            HMGetArguments::parse_response(&response)?,
+            vec![
+                Some(DataType::String("John".into())),
+                None,
+                Some(DataType::Bytes(vec![0xff])),
+            ]
+        );
+
+        Ok(())
+    }
+}
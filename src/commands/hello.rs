@@ -0,0 +1,194 @@
+use crate::{error::CamasError, protocol::ProtocolDataType};
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct HelloArguments {
+    protocol_version: u8,
+}
+
+impl HelloArguments {
+    pub fn new(protocol_version: u8) -> Self {
+        Self { protocol_version }
+    }
+}
+
+impl CommandArguments for HelloArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![ProtocolDataType::BulkString(
+            self.protocol_version.to_string(),
+        )]
+    }
+}
+
+/// Information about the server returned by the `HELLO` handshake
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HelloResponse {
+    pub server: String,
+    pub version: String,
+    pub proto: u8,
+    pub id: i64,
+    pub mode: String,
+    pub role: String,
+    pub modules: Vec<String>,
+}
+
+impl HelloResponse {
+    pub(crate) fn parse(response: &ProtocolDataType) -> Result<Self, CamasError> {
+        let mut hello_response = HelloResponse::default();
+
+        let pairs = pairs(response).ok_or_else(|| CamasError::UnexpectedReply(response.clone()))?;
+
+        for (field, value) in pairs {
+            let text = value.as_str().unwrap_or_default().to_string();
+
+            match (field.as_str().unwrap_or_default(), value) {
+                ("proto", ProtocolDataType::Integer(proto)) => {
+                    hello_response.proto = *proto as u8;
+                }
+                ("id", ProtocolDataType::Integer(id)) => hello_response.id = *id,
+                ("modules", ProtocolDataType::Array(modules)) => {
+                    hello_response.modules = modules.iter().filter_map(module_name).collect();
+                }
+                ("server", _) => hello_response.server = text,
+                ("version", _) => hello_response.version = text,
+                ("mode", _) => hello_response.mode = text,
+                ("role", _) => hello_response.role = text,
+                _ => {}
+            }
+        }
+
+        Ok(hello_response)
+    }
+}
+
+/// Returns the field/value pairs of a reply, which is a map under RESP3 and a
+/// flat array of alternating fields and values under RESP2. Returns `None`
+/// if it's neither.
+fn pairs(response: &ProtocolDataType) -> Option<Vec<(&ProtocolDataType, &ProtocolDataType)>> {
+    match response {
+        ProtocolDataType::Map(pairs) => {
+            Some(pairs.iter().map(|(field, value)| (field, value)).collect())
+        }
+        ProtocolDataType::Array(items) => Some(
+            items
+                .chunks_exact(2)
+                .map(|pair| (&pair[0], &pair[1]))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Finds the `name` field of a module description
+fn module_name(module: &ProtocolDataType) -> Option<String> {
+    pairs(module)?
+        .into_iter()
+        .find(|(field, _)| field.as_str() == Some("name"))
+        .and_then(|(_, value)| value.as_str())
+        .map(String::from)
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = HelloArguments::new(3).to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("3".into())]);
+    }
+}
+
+#[cfg(test)]
+mod response {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn parses_resp2_reply() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(vec![
+            "server".into(),
+            "redis".into(),
+            "version".into(),
+            "7.2.4".into(),
+            "proto".into(),
+            2.into(),
+            "id".into(),
+            7.into(),
+            "mode".into(),
+            "standalone".into(),
+            "role".into(),
+            "master".into(),
+            "modules".into(),
+            ProtocolDataType::Array(vec![ProtocolDataType::Array(vec![
+                "name".into(),
+                "search".into(),
+                "ver".into(),
+                20809.into(),
+            ])]),
+        ]);
+
+        let result = HelloResponse::parse(&response)?;
+
+        assert_eq!(
+            result,
+            HelloResponse {
+                server: "redis".into(),
+                version: "7.2.4".into(),
+                proto: 2,
+                id: 7,
+                mode: "standalone".into(),
+                role: "master".into(),
+                modules: vec!["search".into()],
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_resp3_reply() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Map(vec![
+            ("server".into(), "redis".into()),
+            ("version".into(), "7.2.4".into()),
+            ("proto".into(), 3.into()),
+            ("id".into(), 7.into()),
+            ("mode".into(), "standalone".into()),
+            ("role".into(), "master".into()),
+            (
+                "modules".into(),
+                ProtocolDataType::Array(vec![ProtocolDataType::Map(vec![
+                    ("name".into(), "search".into()),
+                    ("ver".into(), 20809.into()),
+                ])]),
+            ),
+        ]);
+
+        let result = HelloResponse::parse(&response)?;
+
+        assert_eq!(
+            result,
+            HelloResponse {
+                server: "redis".into(),
+                version: "7.2.4".into(),
+                proto: 3,
+                id: 7,
+                mode: "standalone".into(),
+                role: "master".into(),
+                modules: vec!["search".into()],
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_on_unexpected_reply() {
+        assert!(matches!(
+            HelloResponse::parse(&ProtocolDataType::Integer(1)),
+            Err(CamasError::UnexpectedReply(_))
+        ));
+    }
+}
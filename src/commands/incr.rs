@@ -0,0 +1,33 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct IncrArguments {
+    key: String,
+}
+
+impl IncrArguments {
+    pub fn new<K: ToString>(key: K) -> Self {
+        Self {
+            key: key.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for IncrArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![ProtocolDataType::BulkString(self.key.clone())]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = IncrArguments::new("foo").to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("foo".into())]);
+    }
+}
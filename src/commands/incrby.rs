@@ -0,0 +1,142 @@
+use crate::{error::CamasError, protocol::ProtocolDataType};
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct IncrByArguments {
+    key: String,
+    delta: i64,
+}
+
+impl IncrByArguments {
+    pub fn new<K: ToString>(key: K, delta: i64) -> Self {
+        Self {
+            key: key.to_string(),
+            delta,
+        }
+    }
+}
+
+impl CommandArguments for IncrByArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.delta.to_string()),
+        ]
+    }
+}
+
+pub struct IncrByFloatArguments {
+    key: String,
+    delta: f64,
+}
+
+impl IncrByFloatArguments {
+    pub fn new<K: ToString>(key: K, delta: f64) -> Self {
+        Self {
+            key: key.to_string(),
+            delta,
+        }
+    }
+
+    /// Parses the new value of the key out of the `INCRBYFLOAT` response.
+    ///
+    /// Redis replies with a bulk string instead of a number here, and always
+    /// formats it without exponents (e.g. `3000` instead of `3.0e3`).
+    pub(crate) fn parse_response(response: &ProtocolDataType) -> Result<f64, CamasError> {
+        match response {
+            ProtocolDataType::BulkString(value) => value
+                .parse()
+                .map_err(|_| CamasError::Conversion(format!("{} isn't a float", value))),
+            ProtocolDataType::Double(value) => Ok(*value),
+            _ => Err(CamasError::UnexpectedReply(response.clone())),
+        }
+    }
+}
+
+impl CommandArguments for IncrByFloatArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.delta.to_string()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_integer_delta_correctly() {
+        let result = IncrByArguments::new("foo", -42).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("-42".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_float_delta_correctly() {
+        let result = IncrByFloatArguments::new("foo", 0.5).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("0.5".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_float_delta_in_exponential_notation_without_exponent() {
+        let result = IncrByFloatArguments::new("foo", 3.0e3).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("3000".into()),
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod response {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn parses_float_without_fractional_part() -> Result<(), Box<dyn Error>> {
+        let result =
+            IncrByFloatArguments::parse_response(&ProtocolDataType::BulkString("3000".into()))?;
+
+        assert_eq!(result, 3.0e3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_float_with_fractional_part() -> Result<(), Box<dyn Error>> {
+        let result =
+            IncrByFloatArguments::parse_response(&ProtocolDataType::BulkString("10.5".into()))?;
+
+        assert_eq!(result, 10.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_non_numeric_string() {
+        let result =
+            IncrByFloatArguments::parse_response(&ProtocolDataType::BulkString("foo".into()));
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::BTreeMap;
+
+use crate::{error::CamasError, from_redis_value::FromRedisValue, protocol::ProtocolDataType};
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct InfoArguments {
+    section: Option<String>,
+}
+
+impl InfoArguments {
+    pub fn new(section: Option<&str>) -> Self {
+        Self {
+            section: section.map(str::to_string),
+        }
+    }
+}
+
+impl CommandArguments for InfoArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        self.section
+            .iter()
+            .cloned()
+            .map(ProtocolDataType::BulkString)
+            .collect()
+    }
+}
+
+/// The information about the server returned by `INFO`, grouped by section.
+///
+/// Section names are lowercase, like the ones given to `INFO` (e.g.
+/// `server` for the `# Server` section).
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct InfoMap {
+    sections: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl InfoMap {
+    /// Returns the value of a field of a section
+    pub fn get(&self, section: &str, field: &str) -> Option<&str> {
+        self.sections
+            .get(&section.to_lowercase())?
+            .get(field)
+            .map(String::as_str)
+    }
+
+    /// Returns all the fields of a section
+    pub fn section(&self, section: &str) -> Option<&BTreeMap<String, String>> {
+        self.sections.get(&section.to_lowercase())
+    }
+
+    /// Returns all the sections, each with its fields
+    pub fn sections(&self) -> &BTreeMap<String, BTreeMap<String, String>> {
+        &self.sections
+    }
+
+    /// Parses the text returned by `INFO`, which has a `# Section` line
+    /// before the `field:value` lines of each section
+    pub(crate) fn parse(text: &str) -> Self {
+        let mut sections = BTreeMap::new();
+        let mut current_section = None;
+
+        for line in text.lines().map(str::trim) {
+            if let Some(section) = line.strip_prefix('#') {
+                let section = section.trim().to_lowercase();
+
+                sections
+                    .entry(section.clone())
+                    .or_insert_with(BTreeMap::new);
+                current_section = Some(section);
+            } else if let Some((field, value)) = line.split_once(':') {
+                // Fields should always come after a section header, but
+                // they're kept under an unnamed section if they don't
+                let section = current_section.clone().unwrap_or_default();
+
+                sections
+                    .entry(section)
+                    .or_insert_with(BTreeMap::new)
+                    .insert(field.to_string(), value.to_string());
+            }
+        }
+
+        Self { sections }
+    }
+
+    pub(crate) fn parse_response(response: &ProtocolDataType) -> Result<Self, CamasError> {
+        Ok(Self::parse(&String::from_redis_value(response)?))
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_without_section() {
+        let result = InfoArguments::new(None).to_protocol_arguments();
+
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn builds_with_section() {
+        let result = InfoArguments::new(Some("server")).to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("server".into())]);
+    }
+}
+
+#[cfg(test)]
+mod parsing {
+    use super::*;
+
+    const SAMPLE: &str = "# Server\r\n\
+        redis_version:7.2.4\r\n\
+        redis_mode:standalone\r\n\
+        os:Linux 6.1.0-18-amd64 x86_64\r\n\
+        tcp_port:6379\r\n\
+        \r\n\
+        # Clients\r\n\
+        connected_clients:1\r\n\
+        \r\n\
+        # Keyspace\r\n\
+        db0:keys=1,expires=0,avg_ttl=0\r\n";
+
+    #[test]
+    fn parses_fields_by_section() {
+        let result = InfoMap::parse(SAMPLE);
+
+        assert_eq!(result.get("server", "redis_version"), Some("7.2.4"));
+        assert_eq!(result.get("clients", "connected_clients"), Some("1"));
+    }
+
+    #[test]
+    fn parses_values_containing_colons_and_spaces() {
+        let result = InfoMap::parse(SAMPLE);
+
+        assert_eq!(
+            result.get("keyspace", "db0"),
+            Some("keys=1,expires=0,avg_ttl=0")
+        );
+        assert_eq!(
+            result.get("server", "os"),
+            Some("Linux 6.1.0-18-amd64 x86_64")
+        );
+    }
+
+    #[test]
+    fn parses_every_section() {
+        let result = InfoMap::parse(SAMPLE);
+
+        assert_eq!(
+            result.sections().keys().collect::<Vec<_>>(),
+            vec!["clients", "keyspace", "server"]
+        );
+        assert_eq!(result.section("Server").map(BTreeMap::len), Some(4));
+    }
+
+    #[test]
+    fn parses_empty_section() {
+        let result = InfoMap::parse("# Modules\r\n");
+
+        assert_eq!(result.section("modules"), Some(&BTreeMap::new()));
+    }
+
+    #[test]
+    fn returns_nothing_for_missing_field() {
+        let result = InfoMap::parse(SAMPLE);
+
+        assert_eq!(result.get("server", "uptime_in_seconds"), None);
+        assert_eq!(result.get("memory", "used_memory"), None);
+    }
+
+    #[test]
+    fn parses_verbatim_string_reply() -> Result<(), CamasError> {
+        let response = ProtocolDataType::VerbatimString {
+            format: "txt".into(),
+            content: SAMPLE.into(),
+        };
+
+        let result = InfoMap::parse_response(&response)?;
+
+        assert_eq!(result, InfoMap::parse(SAMPLE));
+
+        Ok(())
+    }
+}
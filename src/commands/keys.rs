@@ -0,0 +1,33 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct KeysArguments {
+    pattern: String,
+}
+
+impl KeysArguments {
+    pub fn new<P: ToString>(pattern: P) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for KeysArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![ProtocolDataType::BulkString(self.pattern.clone())]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = KeysArguments::new("user:*").to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("user:*".into())]);
+    }
+}
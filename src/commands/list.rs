@@ -0,0 +1,423 @@
+use std::time::Duration;
+
+use crate::{data_type::DataType, error::CamasError, protocol::ProtocolDataType};
+
+use super::{parse_string, CommandArguments, ProtocolCommandArguments};
+
+pub struct PushArguments {
+    key: String,
+    values: Vec<String>,
+}
+
+impl PushArguments {
+    pub fn new<K, V>(key: K, values: &[V]) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        Self {
+            key: key.to_string(),
+            values: values.iter().map(|value| value.to_string()).collect(),
+        }
+    }
+}
+
+impl CommandArguments for PushArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut arguments = vec![ProtocolDataType::BulkString(self.key.clone())];
+
+        arguments.extend(
+            self.values
+                .iter()
+                .cloned()
+                .map(ProtocolDataType::BulkString),
+        );
+
+        arguments
+    }
+}
+
+pub struct PopArguments {
+    key: String,
+    count: Option<u64>,
+}
+
+impl PopArguments {
+    pub fn new<K: ToString>(key: K, count: Option<u64>) -> Self {
+        Self {
+            key: key.to_string(),
+            count,
+        }
+    }
+
+    /// Converts the reply of `LPOP`/`RPOP` (or `SPOP`) into the popped values.
+    ///
+    /// Without a count, Redis replies with a single bulk string, and with one,
+    /// with an array (or a set). Both are returned as a vector, which is
+    /// `None` if the key is not set.
+    ///
+    /// Values are returned as they are, since they're already gone from the
+    /// key and couldn't be read again if they didn't convert.
+    pub(crate) fn parse_response(
+        response: &ProtocolDataType,
+    ) -> Result<Option<Vec<DataType>>, CamasError> {
+        match response {
+            ProtocolDataType::Null => Ok(None),
+            ProtocolDataType::BulkString(_) | ProtocolDataType::BulkBytes(_) => {
+                Ok(Some(vec![response.try_into()?]))
+            }
+            ProtocolDataType::Array(values) | ProtocolDataType::Set(values) => {
+                let values = values
+                    .iter()
+                    .map(DataType::try_from)
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(Some(values).filter(|values| !values.is_empty()))
+            }
+            _ => Err(CamasError::UnexpectedReply(response.clone())),
+        }
+    }
+}
+
+impl CommandArguments for PopArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut arguments = vec![ProtocolDataType::BulkString(self.key.clone())];
+
+        if let Some(count) = self.count {
+            arguments.push(ProtocolDataType::BulkString(count.to_string()));
+        }
+
+        arguments
+    }
+}
+
+pub struct LLenArguments {
+    key: String,
+}
+
+impl LLenArguments {
+    pub fn new<K: ToString>(key: K) -> Self {
+        Self {
+            key: key.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for LLenArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![ProtocolDataType::BulkString(self.key.clone())]
+    }
+}
+
+pub struct LIndexArguments {
+    key: String,
+    index: i64,
+}
+
+impl LIndexArguments {
+    pub fn new<K: ToString>(key: K, index: i64) -> Self {
+        Self {
+            key: key.to_string(),
+            index,
+        }
+    }
+}
+
+impl CommandArguments for LIndexArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.index.to_string()),
+        ]
+    }
+}
+
+pub struct BLPopArguments {
+    keys: Vec<String>,
+    timeout: Duration,
+}
+
+impl BLPopArguments {
+    pub fn new<K: ToString>(keys: &[K], timeout: Duration) -> Self {
+        Self {
+            keys: keys.iter().map(|key| key.to_string()).collect(),
+            timeout,
+        }
+    }
+
+    /// Converts the reply of `BLPOP`/`BRPOP` into the key a value was popped
+    /// from and the value, which is `None` if the timeout was reached.
+    ///
+    /// The value is returned as it is, since it's already gone from the list
+    /// and couldn't be read again if it didn't convert.
+    pub(crate) fn parse_response(
+        response: &ProtocolDataType,
+    ) -> Result<Option<(String, DataType)>, CamasError> {
+        match response {
+            ProtocolDataType::Null => Ok(None),
+            ProtocolDataType::Array(items) => match items.as_slice() {
+                [key, value] => Ok(Some((parse_string(key)?, value.try_into()?))),
+                _ => Err(CamasError::UnexpectedReply(response.clone())),
+            },
+            _ => Err(CamasError::UnexpectedReply(response.clone())),
+        }
+    }
+}
+
+impl CommandArguments for BLPopArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut arguments = self
+            .keys
+            .iter()
+            .cloned()
+            .map(ProtocolDataType::BulkString)
+            .collect::<Vec<_>>();
+
+        // Redis takes the timeout in seconds, with a fractional part, but
+        // truncates it to milliseconds, so shorter timeouts are rounded up
+        // instead of turning into 0, which would block forever
+        let timeout = match self.timeout {
+            timeout if timeout.is_zero() => timeout,
+            timeout => timeout.max(Duration::from_millis(1)),
+        };
+
+        arguments.push(ProtocolDataType::BulkString(
+            timeout.as_secs_f64().to_string(),
+        ));
+
+        arguments
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_push_correctly() {
+        let result = PushArguments::new("list", &["foo", "bar"]).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("list".into()),
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("bar".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_pop_without_count_correctly() {
+        let result = PopArguments::new("list", None).to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("list".into())]);
+    }
+
+    #[test]
+    fn builds_pop_with_count_correctly() {
+        let result = PopArguments::new("list", Some(2)).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("list".into()),
+                ProtocolDataType::BulkString("2".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_llen_correctly() {
+        let result = LLenArguments::new("list").to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("list".into())]);
+    }
+
+    #[test]
+    fn builds_lindex_with_negative_index_correctly() {
+        let result = LIndexArguments::new("list", -1).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("list".into()),
+                ProtocolDataType::BulkString("-1".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_blpop_correctly() {
+        let result = BLPopArguments::new(&["foo", "bar"], Duration::from_millis(1500))
+            .to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("bar".into()),
+                ProtocolDataType::BulkString("1.5".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn rounds_sub_millisecond_blpop_timeout_up() {
+        let result =
+            BLPopArguments::new(&["foo"], Duration::from_micros(1)).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("0.001".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_zero_blpop_timeout() {
+        let result = BLPopArguments::new(&["foo"], Duration::ZERO).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("0".into()),
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod response {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn parses_single_popped_value() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::BulkString("foo".into());
+
+        assert_eq!(
+            PopArguments::parse_response(&response)?,
+            Some(vec![DataType::String("foo".into())])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_single_popped_binary_value() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::BulkBytes(vec![b'a', 0xff]);
+
+        assert_eq!(
+            PopArguments::parse_response(&response)?,
+            Some(vec![DataType::Bytes(vec![b'a', 0xff])])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_many_popped_values() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("foo".into()),
+            ProtocolDataType::BulkString("bar".into()),
+        ]);
+
+        assert_eq!(
+            PopArguments::parse_response(&response)?,
+            Some(vec![
+                DataType::String("foo".into()),
+                DataType::String("bar".into())
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_many_popped_values_in_set() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Set(vec![ProtocolDataType::BulkString("foo".into())]);
+
+        assert_eq!(
+            PopArguments::parse_response(&response)?,
+            Some(vec![DataType::String("foo".into())])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_missing_key() -> Result<(), Box<dyn Error>> {
+        assert_eq!(PopArguments::parse_response(&ProtocolDataType::Null)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_on_unexpected_reply() {
+        assert!(matches!(
+            PopArguments::parse_response(&ProtocolDataType::Integer(1)),
+            Err(CamasError::UnexpectedReply(_))
+        ));
+    }
+
+    #[test]
+    fn parses_empty_array_as_missing() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(Vec::new());
+
+        assert_eq!(PopArguments::parse_response(&response)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_blocking_pop_into_key_and_value() -> Result<(), CamasError> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("list".into()),
+            ProtocolDataType::BulkString("foo".into()),
+        ]);
+
+        assert_eq!(
+            BLPopArguments::parse_response(&response)?,
+            Some(("list".into(), DataType::String("foo".into())))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_blocking_pop_of_binary_value() -> Result<(), CamasError> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("list".into()),
+            ProtocolDataType::BulkBytes(vec![b'a', 0xff]),
+        ]);
+
+        assert_eq!(
+            BLPopArguments::parse_response(&response)?,
+            Some(("list".into(), DataType::Bytes(vec![b'a', 0xff])))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_blocking_pop_timeout_into_none() -> Result<(), CamasError> {
+        assert_eq!(
+            BLPopArguments::parse_response(&ProtocolDataType::Null)?,
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_on_unexpected_blocking_pop_reply() {
+        let result = BLPopArguments::parse_response(&ProtocolDataType::Integer(1));
+
+        assert!(matches!(result, Err(CamasError::UnexpectedReply(_))));
+    }
+}
@@ -0,0 +1,62 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct LRangeArguments {
+    key: String,
+    start: i64,
+    stop: i64,
+}
+
+impl LRangeArguments {
+    pub fn new<K: ToString>(key: K, start: i64, stop: i64) -> Self {
+        Self {
+            key: key.to_string(),
+            start,
+            stop,
+        }
+    }
+}
+
+impl CommandArguments for LRangeArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.start.to_string()),
+            ProtocolDataType::BulkString(self.stop.to_string()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = LRangeArguments::new("list", 0, 10).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("list".into()),
+                ProtocolDataType::BulkString("0".into()),
+                ProtocolDataType::BulkString("10".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_with_negative_indices() {
+        let result = LRangeArguments::new("list", -3, -1).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("list".into()),
+                ProtocolDataType::BulkString("-3".into()),
+                ProtocolDataType::BulkString("-1".into()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{data_type::DataType, error::CamasError, protocol::ProtocolDataType};
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct MGetArguments {
+    keys: Vec<String>,
+}
+
+impl MGetArguments {
+    pub fn new<K: ToString>(keys: Vec<K>) -> Self {
+        Self {
+            keys: keys.iter().map(|item| item.to_string()).collect(),
+        }
+    }
+
+    /// Converts the array returned by `MGET` into values ordered like the
+    /// requested keys. Keys that aren't set are returned as `None`.
+    pub(crate) fn parse_response(
+        response: &ProtocolDataType,
+    ) -> Result<Vec<Option<DataType>>, CamasError> {
+        let ProtocolDataType::Array(values) = response else {
+            return Err(CamasError::UnexpectedReply(response.clone()));
+        };
+
+        values
+            .iter()
+            .map(|value| match value {
+                ProtocolDataType::Null => Ok(None),
+                value => Ok(Some(value.try_into()?)),
+            })
+            .collect()
+    }
+}
+
+impl CommandArguments for MGetArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        self.keys
+            .iter()
+            .cloned()
+            .map(ProtocolDataType::BulkString)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = MGetArguments::new(vec!["foo", "bar", "baz"]).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("bar".into()),
+                ProtocolDataType::BulkString("baz".into())
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod response {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn parses_mixed_existing_and_missing_keys_in_order() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("Hello".into()),
+            ProtocolDataType::Null,
+            ProtocolDataType::BulkString("World".into()),
+        ]);
+
+        let result = MGetArguments::parse_response(&response)?;
+
+        assert_eq!(
+            result,
+            vec![
+                Some(DataType::String("Hello".into())),
+                None,
+                Some(DataType::String("World".into())),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_only_missing_keys() -> Result<(), Box<dyn Error>> {
+        let response =
+            ProtocolDataType::Array(vec![ProtocolDataType::Null, ProtocolDataType::Null]);
+
+        let result = MGetArguments::parse_response(&response)?;
+
+        assert_eq!(result, vec![None, None]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_on_non_array_reply() {
+        let result = MGetArguments::parse_response(&ProtocolDataType::Integer(1));
+
+        assert!(matches!(result, Err(CamasError::UnexpectedReply(_))));
+    }
+}
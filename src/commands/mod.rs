@@ -1,23 +1,232 @@
-use crate::protocol::ProtocolDataType;
+use crate::{error::CamasError, from_redis_value::FromRedisValue, protocol::ProtocolDataType};
 
-use self::{del::DelArguments, flushdb::FlushDbArguments, get::GetArguments, set::SetArguments};
+use self::{
+    append::AppendArguments,
+    auth::AuthArguments,
+    bit::{BitCountArguments, BitPosArguments, GetBitArguments, SetBitArguments},
+    client::{ClientGetNameArguments, ClientSetNameArguments},
+    command::CommandCountArguments,
+    config::{ConfigGetArguments, ConfigSetArguments},
+    copy::CopyArguments,
+    del::DelArguments,
+    dump::{DumpArguments, RestoreArguments},
+    eval::{EvalArguments, ScriptLoadArguments},
+    expire::ExpireArguments,
+    flushdb::FlushDbArguments,
+    get::GetArguments,
+    getdel::GetDelArguments,
+    getex::GetExArguments,
+    getset::GetSetArguments,
+    hash::{HGetArguments, HKeysArguments, HMGetArguments, HSetArguments},
+    hello::HelloArguments,
+    incr::IncrArguments,
+    incrby::{IncrByArguments, IncrByFloatArguments},
+    info::InfoArguments,
+    keys::KeysArguments,
+    list::{BLPopArguments, LIndexArguments, LLenArguments, PopArguments, PushArguments},
+    lrange::LRangeArguments,
+    mget::MGetArguments,
+    move_key::MoveArguments,
+    object::ObjectEncodingArguments,
+    persist::PersistArguments,
+    persistence::{BgSaveArguments, LastSaveArguments},
+    ping::PingArguments,
+    randomkey::RandomKeyArguments,
+    rename::RenameArguments,
+    scan::{KeyScanArguments, ScanArguments},
+    select::SelectArguments,
+    set::SetArguments,
+    set_cmd::{
+        SAddArguments, SCardArguments, SInterArguments, SIsMemberArguments, SMembersArguments,
+    },
+    setex::SetExArguments,
+    setnx::SetNxArguments,
+    subscribe::SubscribeArguments,
+    time::TimeArguments,
+    touch::TouchArguments,
+    ttl::TtlArguments,
+    type_::TypeArguments,
+    unlink::UnlinkArguments,
+    watch::WatchArguments,
+    zset::{ZAddArguments, ZRangeArguments, ZScoreArguments},
+};
 
-pub(crate) mod del;
+pub mod append;
+pub mod auth;
+pub mod bit;
+pub mod client;
+pub mod command;
+pub mod config;
+pub mod copy;
+pub mod del;
+pub mod dump;
+pub mod eval;
+pub mod expire;
 pub mod flushdb;
-pub(crate) mod get;
+pub mod get;
+pub mod getdel;
+pub mod getex;
+pub mod getset;
+pub mod hash;
+pub mod hello;
+pub mod incr;
+pub mod incrby;
+pub mod info;
+pub mod keys;
+pub mod list;
+pub mod lrange;
+pub mod mget;
+pub mod move_key;
+pub mod object;
+pub mod persist;
+pub mod persistence;
+pub mod ping;
+pub mod randomkey;
+pub mod rename;
+pub mod scan;
+pub mod select;
 pub mod set;
+pub mod set_cmd;
+pub mod setex;
+pub mod setnx;
+pub mod subscribe;
+pub mod time;
+pub mod touch;
+pub mod ttl;
+pub mod type_;
+pub mod unlink;
+pub mod watch;
+pub mod zset;
 
 pub type ProtocolCommandArguments = Vec<ProtocolDataType>;
 
-pub(super) trait CommandArguments {
+/// The arguments of a command, which are sent right after its name
+pub trait CommandArguments {
     fn to_protocol_arguments(&self) -> ProtocolCommandArguments;
 }
 
-pub(crate) enum Command {
+/// Reads a single value of a reply as a string. Values that aren't valid
+/// UTF-8 have their invalid sequences replaced, since they can't be returned
+/// as they are.
+pub(crate) fn parse_string(value: &ProtocolDataType) -> Result<String, CamasError> {
+    match value {
+        ProtocolDataType::BulkBytes(bytes) => Ok(String::from_utf8_lossy(bytes).into_owned()),
+        value => String::from_redis_value(value),
+    }
+}
+
+/// Extracts the strings out of an array (or set) response, like the ones
+/// returned by commands that list keys or members.
+///
+/// Items that aren't valid UTF-8 are decoded like [`parse_string`] does.
+pub(crate) fn parse_string_array(response: &ProtocolDataType) -> Result<Vec<String>, CamasError> {
+    let (ProtocolDataType::Array(items) | ProtocolDataType::Set(items)) = response else {
+        return Err(CamasError::UnexpectedReply(response.clone()));
+    };
+
+    items.iter().map(parse_string).collect()
+}
+
+/// A command that can be sent to Redis
+pub enum Command {
     Set(SetArguments),
     Get(GetArguments),
     Del(DelArguments),
     FlushDb(FlushDbArguments),
+    Expire(ExpireArguments),
+    Ttl(TtlArguments),
+    Pttl(TtlArguments),
+    Incr(IncrArguments),
+    Decr(IncrArguments),
+    IncrBy(IncrByArguments),
+    DecrBy(IncrByArguments),
+    IncrByFloat(IncrByFloatArguments),
+    MGet(MGetArguments),
+    Append(AppendArguments),
+    GetDel(GetDelArguments),
+    GetEx(GetExArguments),
+    Keys(KeysArguments),
+    Type(TypeArguments),
+    Rename(RenameArguments),
+    RenameNx(RenameArguments),
+    Select(SelectArguments),
+    Auth(AuthArguments),
+    Hello(HelloArguments),
+    LPush(PushArguments),
+    RPush(PushArguments),
+    LPop(PopArguments),
+    RPop(PopArguments),
+    LRange(LRangeArguments),
+    LLen(LLenArguments),
+    LIndex(LIndexArguments),
+    HSet(HSetArguments),
+    HGet(HGetArguments),
+    SAdd(SAddArguments),
+    SMembers(SMembersArguments),
+    SIsMember(SIsMemberArguments),
+    SCard(SCardArguments),
+    SRem(SAddArguments),
+    SPop(PopArguments),
+    ZAdd(ZAddArguments),
+    ZRange(ZRangeArguments),
+    ZScore(ZScoreArguments),
+    ZRank(ZScoreArguments),
+    Subscribe(SubscribeArguments),
+    Unsubscribe(SubscribeArguments),
+    Watch(WatchArguments),
+    Unwatch,
+    Multi,
+    Exec,
+    Eval(EvalArguments),
+    ScriptLoad(ScriptLoadArguments),
+    EvalSha(EvalArguments),
+    ConfigGet(ConfigGetArguments),
+    ConfigSet(ConfigSetArguments),
+    Info(InfoArguments),
+    ExpireAt(ExpireArguments),
+    PExpireAt(ExpireArguments),
+    Persist(PersistArguments),
+    Copy(CopyArguments),
+    Unlink(UnlinkArguments),
+    Touch(TouchArguments),
+    RandomKey(RandomKeyArguments),
+    SetBit(SetBitArguments),
+    GetBit(GetBitArguments),
+    BitCount(BitCountArguments),
+    ObjectEncoding(ObjectEncodingArguments),
+    Time(TimeArguments),
+    LastSave(LastSaveArguments),
+    BgSave(BgSaveArguments),
+    SMIsMember(SAddArguments),
+    HMGet(HMGetArguments),
+    HKeys(HKeysArguments),
+    HVals(HKeysArguments),
+    SInter(SInterArguments),
+    SUnion(SInterArguments),
+    SDiff(SInterArguments),
+    BLPop(BLPopArguments),
+    BRPop(BLPopArguments),
+    CommandCount(CommandCountArguments),
+    GetSet(GetSetArguments),
+    SetEx(SetExArguments),
+    PSetEx(SetExArguments),
+    SetNx(SetNxArguments),
+    Move(MoveArguments),
+    Dump(DumpArguments),
+    Restore(RestoreArguments),
+    Ping(PingArguments),
+    ClientSetName(ClientSetNameArguments),
+    ClientGetName(ClientGetNameArguments),
+    Scan(ScanArguments),
+    HScan(KeyScanArguments),
+    SScan(KeyScanArguments),
+    ZScan(KeyScanArguments),
+    BitPos(BitPosArguments),
+    /// A command that doesn't have a variant of its own
+    Custom {
+        name: String,
+        args: Vec<ProtocolDataType>,
+    },
 }
 
 impl Command {
@@ -27,6 +236,96 @@ impl Command {
             Command::Get(_) => "GET",
             Command::Del(_) => "DEL",
             Command::FlushDb(_) => "FLUSHDB",
+            Command::Expire(_) => "EXPIRE",
+            Command::Ttl(_) => "TTL",
+            Command::Pttl(_) => "PTTL",
+            Command::Incr(_) => "INCR",
+            Command::Decr(_) => "DECR",
+            Command::IncrBy(_) => "INCRBY",
+            Command::DecrBy(_) => "DECRBY",
+            Command::IncrByFloat(_) => "INCRBYFLOAT",
+            Command::MGet(_) => "MGET",
+            Command::Append(_) => "APPEND",
+            Command::GetDel(_) => "GETDEL",
+            Command::GetEx(_) => "GETEX",
+            Command::Keys(_) => "KEYS",
+            Command::Type(_) => "TYPE",
+            Command::Rename(_) => "RENAME",
+            Command::RenameNx(_) => "RENAMENX",
+            Command::Select(_) => "SELECT",
+            Command::Auth(_) => "AUTH",
+            Command::Hello(_) => "HELLO",
+            Command::LPush(_) => "LPUSH",
+            Command::RPush(_) => "RPUSH",
+            Command::LPop(_) => "LPOP",
+            Command::RPop(_) => "RPOP",
+            Command::LRange(_) => "LRANGE",
+            Command::LLen(_) => "LLEN",
+            Command::LIndex(_) => "LINDEX",
+            Command::HSet(_) => "HSET",
+            Command::HGet(_) => "HGET",
+            Command::SAdd(_) => "SADD",
+            Command::SMembers(_) => "SMEMBERS",
+            Command::SIsMember(_) => "SISMEMBER",
+            Command::SCard(_) => "SCARD",
+            Command::SRem(_) => "SREM",
+            Command::SPop(_) => "SPOP",
+            Command::ZAdd(_) => "ZADD",
+            Command::ZRange(_) => "ZRANGE",
+            Command::ZScore(_) => "ZSCORE",
+            Command::ZRank(_) => "ZRANK",
+            Command::Subscribe(_) => "SUBSCRIBE",
+            Command::Unsubscribe(_) => "UNSUBSCRIBE",
+            Command::Watch(_) => "WATCH",
+            Command::Unwatch => "UNWATCH",
+            Command::Multi => "MULTI",
+            Command::Exec => "EXEC",
+            Command::Eval(_) => "EVAL",
+            Command::ScriptLoad(_) => "SCRIPT",
+            Command::EvalSha(_) => "EVALSHA",
+            Command::ConfigGet(_) => "CONFIG",
+            Command::ConfigSet(_) => "CONFIG",
+            Command::Info(_) => "INFO",
+            Command::ExpireAt(_) => "EXPIREAT",
+            Command::PExpireAt(_) => "PEXPIREAT",
+            Command::Persist(_) => "PERSIST",
+            Command::Copy(_) => "COPY",
+            Command::Unlink(_) => "UNLINK",
+            Command::Touch(_) => "TOUCH",
+            Command::RandomKey(_) => "RANDOMKEY",
+            Command::SetBit(_) => "SETBIT",
+            Command::GetBit(_) => "GETBIT",
+            Command::BitCount(_) => "BITCOUNT",
+            Command::ObjectEncoding(_) => "OBJECT",
+            Command::Time(_) => "TIME",
+            Command::LastSave(_) => "LASTSAVE",
+            Command::BgSave(_) => "BGSAVE",
+            Command::SMIsMember(_) => "SMISMEMBER",
+            Command::HMGet(_) => "HMGET",
+            Command::HKeys(_) => "HKEYS",
+            Command::HVals(_) => "HVALS",
+            Command::SInter(_) => "SINTER",
+            Command::SUnion(_) => "SUNION",
+            Command::SDiff(_) => "SDIFF",
+            Command::BLPop(_) => "BLPOP",
+            Command::BRPop(_) => "BRPOP",
+            Command::CommandCount(_) => "COMMAND",
+            Command::GetSet(_) => "GETSET",
+            Command::SetEx(_) => "SETEX",
+            Command::PSetEx(_) => "PSETEX",
+            Command::SetNx(_) => "SETNX",
+            Command::Move(_) => "MOVE",
+            Command::Dump(_) => "DUMP",
+            Command::Restore(_) => "RESTORE",
+            Command::Ping(_) => "PING",
+            Command::ClientSetName(_) => "CLIENT",
+            Command::ClientGetName(_) => "CLIENT",
+            Command::Scan(_) => "SCAN",
+            Command::HScan(_) => "HSCAN",
+            Command::SScan(_) => "SSCAN",
+            Command::ZScan(_) => "ZSCAN",
+            Command::BitPos(_) => "BITPOS",
+            Command::Custom { name, .. } => name,
         }
     }
 
@@ -36,10 +335,114 @@ impl Command {
             Command::Get(arguments) => arguments.to_protocol_arguments(),
             Command::Del(arguments) => arguments.to_protocol_arguments(),
             Command::FlushDb(arguments) => arguments.to_protocol_arguments(),
+            Command::Expire(arguments) => arguments.to_protocol_arguments(),
+            Command::Ttl(arguments) => arguments.to_protocol_arguments(),
+            Command::Pttl(arguments) => arguments.to_protocol_arguments(),
+            Command::Incr(arguments) => arguments.to_protocol_arguments(),
+            Command::Decr(arguments) => arguments.to_protocol_arguments(),
+            Command::IncrBy(arguments) => arguments.to_protocol_arguments(),
+            Command::DecrBy(arguments) => arguments.to_protocol_arguments(),
+            Command::IncrByFloat(arguments) => arguments.to_protocol_arguments(),
+            Command::MGet(arguments) => arguments.to_protocol_arguments(),
+            Command::Append(arguments) => arguments.to_protocol_arguments(),
+            Command::GetDel(arguments) => arguments.to_protocol_arguments(),
+            Command::GetEx(arguments) => arguments.to_protocol_arguments(),
+            Command::Keys(arguments) => arguments.to_protocol_arguments(),
+            Command::Type(arguments) => arguments.to_protocol_arguments(),
+            Command::Rename(arguments) => arguments.to_protocol_arguments(),
+            Command::RenameNx(arguments) => arguments.to_protocol_arguments(),
+            Command::Select(arguments) => arguments.to_protocol_arguments(),
+            Command::Auth(arguments) => arguments.to_protocol_arguments(),
+            Command::Hello(arguments) => arguments.to_protocol_arguments(),
+            Command::LPush(arguments) => arguments.to_protocol_arguments(),
+            Command::RPush(arguments) => arguments.to_protocol_arguments(),
+            Command::LPop(arguments) => arguments.to_protocol_arguments(),
+            Command::RPop(arguments) => arguments.to_protocol_arguments(),
+            Command::LRange(arguments) => arguments.to_protocol_arguments(),
+            Command::LLen(arguments) => arguments.to_protocol_arguments(),
+            Command::LIndex(arguments) => arguments.to_protocol_arguments(),
+            Command::HSet(arguments) => arguments.to_protocol_arguments(),
+            Command::HGet(arguments) => arguments.to_protocol_arguments(),
+            Command::SAdd(arguments) => arguments.to_protocol_arguments(),
+            Command::SMembers(arguments) => arguments.to_protocol_arguments(),
+            Command::SIsMember(arguments) => arguments.to_protocol_arguments(),
+            Command::SCard(arguments) => arguments.to_protocol_arguments(),
+            Command::SRem(arguments) => arguments.to_protocol_arguments(),
+            Command::SPop(arguments) => arguments.to_protocol_arguments(),
+            Command::ZAdd(arguments) => arguments.to_protocol_arguments(),
+            Command::ZRange(arguments) => arguments.to_protocol_arguments(),
+            Command::ZScore(arguments) => arguments.to_protocol_arguments(),
+            Command::ZRank(arguments) => arguments.to_protocol_arguments(),
+            Command::Subscribe(arguments) => arguments.to_protocol_arguments(),
+            Command::Unsubscribe(arguments) => arguments.to_protocol_arguments(),
+            Command::Watch(arguments) => arguments.to_protocol_arguments(),
+            Command::Unwatch | Command::Multi | Command::Exec => Vec::new(),
+            Command::Eval(arguments) => arguments.to_protocol_arguments(),
+            Command::ScriptLoad(arguments) => arguments.to_protocol_arguments(),
+            Command::EvalSha(arguments) => arguments.to_protocol_arguments(),
+            Command::ConfigGet(arguments) => arguments.to_protocol_arguments(),
+            Command::ConfigSet(arguments) => arguments.to_protocol_arguments(),
+            Command::Info(arguments) => arguments.to_protocol_arguments(),
+            Command::ExpireAt(arguments) => arguments.to_protocol_arguments(),
+            Command::PExpireAt(arguments) => arguments.to_protocol_arguments(),
+            Command::Persist(arguments) => arguments.to_protocol_arguments(),
+            Command::Copy(arguments) => arguments.to_protocol_arguments(),
+            Command::Unlink(arguments) => arguments.to_protocol_arguments(),
+            Command::Touch(arguments) => arguments.to_protocol_arguments(),
+            Command::RandomKey(arguments) => arguments.to_protocol_arguments(),
+            Command::SetBit(arguments) => arguments.to_protocol_arguments(),
+            Command::GetBit(arguments) => arguments.to_protocol_arguments(),
+            Command::BitCount(arguments) => arguments.to_protocol_arguments(),
+            Command::ObjectEncoding(arguments) => arguments.to_protocol_arguments(),
+            Command::Time(arguments) => arguments.to_protocol_arguments(),
+            Command::LastSave(arguments) => arguments.to_protocol_arguments(),
+            Command::BgSave(arguments) => arguments.to_protocol_arguments(),
+            Command::SMIsMember(arguments) => arguments.to_protocol_arguments(),
+            Command::HMGet(arguments) => arguments.to_protocol_arguments(),
+            Command::HKeys(arguments) => arguments.to_protocol_arguments(),
+            Command::HVals(arguments) => arguments.to_protocol_arguments(),
+            Command::SInter(arguments) => arguments.to_protocol_arguments(),
+            Command::SUnion(arguments) => arguments.to_protocol_arguments(),
+            Command::SDiff(arguments) => arguments.to_protocol_arguments(),
+            Command::BLPop(arguments) => arguments.to_protocol_arguments(),
+            Command::BRPop(arguments) => arguments.to_protocol_arguments(),
+            Command::CommandCount(arguments) => arguments.to_protocol_arguments(),
+            Command::GetSet(arguments) => arguments.to_protocol_arguments(),
+            Command::SetEx(arguments) => arguments.to_protocol_arguments(),
+            Command::PSetEx(arguments) => arguments.to_protocol_arguments(),
+            Command::SetNx(arguments) => arguments.to_protocol_arguments(),
+            Command::Move(arguments) => arguments.to_protocol_arguments(),
+            Command::Dump(arguments) => arguments.to_protocol_arguments(),
+            Command::Restore(arguments) => arguments.to_protocol_arguments(),
+            Command::Ping(arguments) => arguments.to_protocol_arguments(),
+            Command::ClientSetName(arguments) => arguments.to_protocol_arguments(),
+            Command::ClientGetName(arguments) => arguments.to_protocol_arguments(),
+            Command::Scan(arguments) => arguments.to_protocol_arguments(),
+            Command::HScan(arguments) => arguments.to_protocol_arguments(),
+            Command::SScan(arguments) => arguments.to_protocol_arguments(),
+            Command::ZScan(arguments) => arguments.to_protocol_arguments(),
+            Command::BitPos(arguments) => arguments.to_protocol_arguments(),
+            Command::Custom { args, .. } => args.clone(),
         }
     }
 
-    pub fn serialize(&self) -> String {
+    /// Checks that the command has the arguments Redis requires, so that a
+    /// malformed command is rejected before anything is sent.
+    pub fn validate(&self) -> Result<(), CamasError> {
+        match self {
+            Command::Del(_) | Command::MGet(_) | Command::Unlink(_) | Command::Touch(_)
+                if self.argument_list().is_empty() =>
+            {
+                Err(CamasError::InvalidArgument(format!(
+                    "{} needs at least one key",
+                    self.command_name()
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
         let mut arguments = Vec::new();
 
         arguments.push(ProtocolDataType::BulkString(self.command_name().into()));
@@ -49,3 +452,140 @@ impl Command {
         ProtocolDataType::Array(arguments).serialize()
     }
 }
+
+#[cfg(test)]
+mod custom {
+    use super::*;
+
+    #[test]
+    fn builds_name_and_arguments() {
+        let command = Command::Custom {
+            name: "OBJECT".into(),
+            args: vec![
+                ProtocolDataType::BulkString("ENCODING".into()),
+                ProtocolDataType::BulkString("foo".into()),
+            ],
+        };
+
+        assert_eq!(command.command_name(), "OBJECT");
+        assert_eq!(
+            command.argument_list(),
+            vec![
+                ProtocolDataType::BulkString("ENCODING".into()),
+                ProtocolDataType::BulkString("foo".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn serializes_correctly() {
+        let command = Command::Custom {
+            name: "DBSIZE".into(),
+            args: vec![],
+        };
+
+        assert_eq!(command.serialize(), b"*1\r\n$6\r\nDBSIZE\r\n");
+    }
+
+    #[test]
+    fn serializes_non_string_arguments_as_given() {
+        let command = Command::Custom {
+            name: "EXPIRE".into(),
+            args: vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::Integer(10),
+            ],
+        };
+
+        assert_eq!(
+            command.serialize(),
+            b"*3\r\n$6\r\nEXPIRE\r\n$3\r\nfoo\r\n:10\r\n"
+        );
+    }
+}
+
+#[cfg(test)]
+mod string_array {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn parses_bulk_strings() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("foo".into()),
+            ProtocolDataType::BulkString("bar".into()),
+        ]);
+
+        assert_eq!(parse_string_array(&response)?, vec!["foo", "bar"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_set() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Set(vec![ProtocolDataType::BulkString("foo".into())]);
+
+        assert_eq!(parse_string_array(&response)?, vec!["foo"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_empty_array() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(Vec::new());
+
+        assert!(parse_string_array(&response)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn decodes_binary_items_without_quoting_them() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkBytes(vec![b'a', 0xff, 0x00]),
+            ProtocolDataType::BulkString("foo".into()),
+        ]);
+
+        assert_eq!(parse_string_array(&response)?, vec!["a\u{fffd}\0", "foo"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_on_unexpected_reply() {
+        assert!(matches!(
+            parse_string_array(&ProtocolDataType::Integer(1)),
+            Err(CamasError::UnexpectedReply(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod validation {
+    use super::*;
+
+    #[test]
+    fn rejects_keyless_commands() {
+        let empty: Vec<&str> = vec![];
+
+        for command in [
+            Command::Del(DelArguments::new(empty.clone())),
+            Command::MGet(MGetArguments::new(empty.clone())),
+            Command::Unlink(UnlinkArguments::new(empty.clone())),
+            Command::Touch(TouchArguments::new(empty.clone())),
+        ] {
+            assert!(matches!(
+                command.validate(),
+                Err(CamasError::InvalidArgument(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn accepts_commands_with_keys() {
+        assert!(Command::Del(DelArguments::new(vec!["foo"]))
+            .validate()
+            .is_ok());
+    }
+}
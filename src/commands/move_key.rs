@@ -0,0 +1,44 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct MoveArguments {
+    key: String,
+    database: u32,
+}
+
+impl MoveArguments {
+    pub fn new<K: ToString>(key: K, database: u32) -> Self {
+        Self {
+            key: key.to_string(),
+            database,
+        }
+    }
+}
+
+impl CommandArguments for MoveArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.database.to_string()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = MoveArguments::new("foo", 1).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("1".into()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct ObjectEncodingArguments {
+    key: String,
+}
+
+impl ObjectEncodingArguments {
+    pub fn new<K: ToString>(key: K) -> Self {
+        Self {
+            key: key.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for ObjectEncodingArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString("ENCODING".into()),
+            ProtocolDataType::BulkString(self.key.clone()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use crate::commands::Command;
+
+    use super::*;
+
+    #[test]
+    fn serializes_object_encoding_correctly() {
+        let command = Command::ObjectEncoding(ObjectEncodingArguments::new("foo"));
+
+        assert_eq!(
+            command.serialize(),
+            b"*3\r\n$6\r\nOBJECT\r\n$8\r\nENCODING\r\n$3\r\nfoo\r\n"
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use super::{CommandArguments, ProtocolCommandArguments};
+
+#[derive(Default)]
+pub struct LastSaveArguments;
+
+impl LastSaveArguments {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CommandArguments for LastSaveArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        Vec::new()
+    }
+}
+
+#[derive(Default)]
+pub struct BgSaveArguments;
+
+impl BgSaveArguments {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CommandArguments for BgSaveArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use crate::commands::Command;
+
+    use super::*;
+
+    #[test]
+    fn serializes_lastsave_correctly() {
+        let command = Command::LastSave(LastSaveArguments::new());
+
+        assert_eq!(command.serialize(), b"*1\r\n$8\r\nLASTSAVE\r\n");
+    }
+
+    #[test]
+    fn serializes_bgsave_correctly() {
+        let command = Command::BgSave(BgSaveArguments::new());
+
+        assert_eq!(command.serialize(), b"*1\r\n$6\r\nBGSAVE\r\n");
+    }
+}
@@ -0,0 +1,30 @@
+use super::{CommandArguments, ProtocolCommandArguments};
+
+#[derive(Default)]
+pub struct PingArguments;
+
+impl PingArguments {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CommandArguments for PingArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use crate::commands::Command;
+
+    use super::*;
+
+    #[test]
+    fn serializes_ping_correctly() {
+        let command = Command::Ping(PingArguments::new());
+
+        assert_eq!(command.serialize(), b"*1\r\n$4\r\nPING\r\n");
+    }
+}
@@ -0,0 +1,28 @@
+use super::{CommandArguments, ProtocolCommandArguments};
+
+#[derive(Default)]
+pub struct RandomKeyArguments;
+
+impl RandomKeyArguments {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CommandArguments for RandomKeyArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = RandomKeyArguments::new().to_protocol_arguments();
+
+        assert!(result.is_empty());
+    }
+}
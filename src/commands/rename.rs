@@ -0,0 +1,44 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct RenameArguments {
+    source: String,
+    destination: String,
+}
+
+impl RenameArguments {
+    pub fn new<K: ToString>(source: K, destination: K) -> Self {
+        Self {
+            source: source.to_string(),
+            destination: destination.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for RenameArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString(self.source.clone()),
+            ProtocolDataType::BulkString(self.destination.clone()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = RenameArguments::new("foo", "bar").to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("bar".into()),
+            ]
+        );
+    }
+}
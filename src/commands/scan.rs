@@ -0,0 +1,396 @@
+use derive_builder::Builder;
+
+use crate::{error::CamasError, from_redis_value::FromRedisValue, protocol::ProtocolDataType};
+
+use super::{parse_string, type_::KeyType, CommandArguments, ProtocolCommandArguments};
+
+/// Filters for the keys returned by `SCAN`.
+///
+/// Filters are applied after keys are retrieved, so a round may return fewer
+/// keys than `count` (or none at all) even if iteration isn't over.
+#[derive(Default, Builder, Clone, Debug, PartialEq, Eq)]
+#[builder(setter(strip_option))]
+#[builder(default)]
+pub struct ScanOptions {
+    /// Only returns keys matching this glob-style pattern (`MATCH`)
+    #[builder(setter(into))]
+    pub pattern: Option<String>,
+    /// How many keys to look at in each round, 10 by default (`COUNT`)
+    pub count: Option<u32>,
+    /// Only returns keys holding this type of value (`TYPE`), which
+    /// requires Redis 6
+    pub type_filter: Option<KeyType>,
+}
+
+impl ScanOptions {
+    pub(crate) fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut arguments = pattern_and_count_arguments(self.pattern.as_ref(), self.count);
+
+        if let Some(type_filter) = self.type_filter {
+            arguments.push(ProtocolDataType::BulkString("TYPE".into()));
+            arguments.push(ProtocolDataType::BulkString(type_filter.to_string()));
+        }
+
+        arguments
+    }
+}
+
+/// Filters for the elements returned by `HSCAN`, `SSCAN` and `ZSCAN`, which,
+/// unlike `SCAN`, can't filter by type.
+///
+/// Filters are applied after elements are retrieved, so a round may return
+/// fewer elements than `count` (or none at all) even if iteration isn't
+/// over.
+#[derive(Default, Builder, Clone, Debug, PartialEq, Eq)]
+#[builder(setter(strip_option))]
+#[builder(default)]
+pub struct KeyScanOptions {
+    /// Only returns elements (fields, for hashes) matching this glob-style
+    /// pattern (`MATCH`)
+    #[builder(setter(into))]
+    pub pattern: Option<String>,
+    /// How many elements to look at in each round, 10 by default (`COUNT`)
+    pub count: Option<u32>,
+}
+
+impl KeyScanOptions {
+    pub(crate) fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        pattern_and_count_arguments(self.pattern.as_ref(), self.count)
+    }
+}
+
+/// Builds the `MATCH` and `COUNT` arguments shared by all the `SCAN` family
+/// of commands
+fn pattern_and_count_arguments(
+    pattern: Option<&String>,
+    count: Option<u32>,
+) -> ProtocolCommandArguments {
+    let mut arguments = Vec::new();
+
+    if let Some(pattern) = pattern {
+        arguments.push(ProtocolDataType::BulkString("MATCH".into()));
+        arguments.push(ProtocolDataType::BulkString(pattern.clone()));
+    }
+
+    if let Some(count) = count {
+        arguments.push(ProtocolDataType::BulkString("COUNT".into()));
+        arguments.push(ProtocolDataType::BulkString(count.to_string()));
+    }
+
+    arguments
+}
+
+pub struct ScanArguments {
+    cursor: u64,
+    options: ScanOptions,
+}
+
+impl ScanArguments {
+    pub fn new(cursor: u64, options: ScanOptions) -> Self {
+        Self { cursor, options }
+    }
+
+    /// Converts the reply of a `SCAN` round into the cursor of the next
+    /// round, which is 0 once iteration is over, and the keys returned.
+    pub(crate) fn parse_response(
+        response: &ProtocolDataType,
+    ) -> Result<(u64, Vec<String>), CamasError> {
+        let (cursor, keys) = parse_scan_response(response)?;
+
+        let keys = keys.iter().map(parse_string).collect::<Result<_, _>>()?;
+
+        Ok((cursor, keys))
+    }
+}
+
+impl CommandArguments for ScanArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut arguments = vec![ProtocolDataType::BulkString(self.cursor.to_string())];
+
+        arguments.extend(self.options.to_protocol_arguments());
+
+        arguments
+    }
+}
+
+/// Splits the reply of any of the `SCAN` family of commands into the cursor
+/// of the next round and the items returned.
+fn parse_scan_response(
+    response: &ProtocolDataType,
+) -> Result<(u64, &[ProtocolDataType]), CamasError> {
+    let unexpected_reply = || CamasError::UnexpectedReply(response.clone());
+
+    let ProtocolDataType::Array(reply) = response else {
+        return Err(unexpected_reply());
+    };
+
+    let [cursor, ProtocolDataType::Array(items) | ProtocolDataType::Set(items)] = reply.as_slice()
+    else {
+        return Err(unexpected_reply());
+    };
+
+    let cursor = String::from_redis_value(cursor)?
+        .parse()
+        .map_err(|_| unexpected_reply())?;
+
+    Ok((cursor, items))
+}
+
+/// Arguments of the commands that iterate over the elements of a key, i.e.
+/// `HSCAN`, `SSCAN` and `ZSCAN`.
+pub struct KeyScanArguments {
+    key: String,
+    cursor: u64,
+    options: KeyScanOptions,
+}
+
+impl KeyScanArguments {
+    pub fn new<K: ToString>(key: K, cursor: u64, options: KeyScanOptions) -> Self {
+        Self {
+            key: key.to_string(),
+            cursor,
+            options,
+        }
+    }
+
+    /// Converts the reply of an `SSCAN` round into the cursor of the next
+    /// round and the members returned.
+    pub(crate) fn parse_members(
+        response: &ProtocolDataType,
+    ) -> Result<(u64, Vec<String>), CamasError> {
+        ScanArguments::parse_response(response)
+    }
+
+    /// Converts the reply of an `HSCAN` round into the cursor of the next
+    /// round and the field/value pairs returned.
+    pub(crate) fn parse_fields(
+        response: &ProtocolDataType,
+    ) -> Result<(u64, Vec<(String, String)>), CamasError> {
+        Self::parse_pairs(response, parse_string)
+    }
+
+    /// Converts the reply of a `ZSCAN` round into the cursor of the next
+    /// round and the member/score pairs returned.
+    pub(crate) fn parse_scores(
+        response: &ProtocolDataType,
+    ) -> Result<(u64, Vec<(String, f64)>), CamasError> {
+        Self::parse_pairs(response, f64::from_redis_value)
+    }
+
+    fn parse_pairs<T>(
+        response: &ProtocolDataType,
+        parse_value: fn(&ProtocolDataType) -> Result<T, CamasError>,
+    ) -> Result<(u64, Vec<(String, T)>), CamasError> {
+        let (cursor, items) = parse_scan_response(response)?;
+
+        if items.len() % 2 != 0 {
+            return Err(CamasError::UnexpectedReply(response.clone()));
+        }
+
+        let pairs = items
+            .chunks_exact(2)
+            .map(|pair| Ok((parse_string(&pair[0])?, parse_value(&pair[1])?)))
+            .collect::<Result<_, CamasError>>()?;
+
+        Ok((cursor, pairs))
+    }
+}
+
+impl CommandArguments for KeyScanArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut arguments = vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.cursor.to_string()),
+        ];
+
+        arguments.extend(self.options.to_protocol_arguments());
+
+        arguments
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly_without_options() {
+        let result = ScanArguments::new(0, Default::default()).to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("0".into())]);
+    }
+
+    #[test]
+    fn builds_correctly_with_all_options() {
+        let options = ScanOptions {
+            pattern: Some("user:*".into()),
+            count: Some(100),
+            type_filter: Some(KeyType::String),
+        };
+
+        let result = ScanArguments::new(17, options).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("17".into()),
+                ProtocolDataType::BulkString("MATCH".into()),
+                ProtocolDataType::BulkString("user:*".into()),
+                ProtocolDataType::BulkString("COUNT".into()),
+                ProtocolDataType::BulkString("100".into()),
+                ProtocolDataType::BulkString("TYPE".into()),
+                ProtocolDataType::BulkString("string".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_correctly_for_a_key() {
+        let options = KeyScanOptions {
+            count: Some(50),
+            ..Default::default()
+        };
+
+        let result = KeyScanArguments::new("myhash", 3, options).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("myhash".into()),
+                ProtocolDataType::BulkString("3".into()),
+                ProtocolDataType::BulkString("COUNT".into()),
+                ProtocolDataType::BulkString("50".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builder_sets_options() {
+        let options = ScanOptionsBuilder::default()
+            .pattern("user:*")
+            .type_filter(KeyType::List)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            options,
+            ScanOptions {
+                pattern: Some("user:*".into()),
+                count: None,
+                type_filter: Some(KeyType::List),
+            }
+        );
+    }
+
+    #[test]
+    fn key_scan_builder_sets_options() {
+        let options = KeyScanOptionsBuilder::default()
+            .pattern("name:*")
+            .count(20)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            options,
+            KeyScanOptions {
+                pattern: Some("name:*".into()),
+                count: Some(20),
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod response {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn parses_cursor_and_keys() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("17".into()),
+            ProtocolDataType::Array(vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("bar".into()),
+            ]),
+        ]);
+
+        assert_eq!(
+            ScanArguments::parse_response(&response)?,
+            (17, vec!["foo".to_string(), "bar".to_string()])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_on_invalid_cursor() {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("nope".into()),
+            ProtocolDataType::Array(vec![]),
+        ]);
+
+        assert!(matches!(
+            ScanArguments::parse_response(&response),
+            Err(CamasError::UnexpectedReply(_))
+        ));
+    }
+
+    #[test]
+    fn parses_field_value_pairs() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("0".into()),
+            ProtocolDataType::Array(vec![
+                ProtocolDataType::BulkString("name".into()),
+                ProtocolDataType::BulkString("john".into()),
+                ProtocolDataType::BulkString("age".into()),
+                ProtocolDataType::BulkString("42".into()),
+            ]),
+        ]);
+
+        assert_eq!(
+            KeyScanArguments::parse_fields(&response)?,
+            (
+                0,
+                vec![
+                    ("name".to_string(), "john".to_string()),
+                    ("age".to_string(), "42".to_string())
+                ]
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_member_score_pairs() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("0".into()),
+            ProtocolDataType::Array(vec![
+                ProtocolDataType::BulkString("one".into()),
+                ProtocolDataType::BulkString("1.5".into()),
+            ]),
+        ]);
+
+        assert_eq!(
+            KeyScanArguments::parse_scores(&response)?,
+            (0, vec![("one".to_string(), 1.5)])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_on_unpaired_items() {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("0".into()),
+            ProtocolDataType::Array(vec![ProtocolDataType::BulkString("name".into())]),
+        ]);
+
+        assert!(matches!(
+            KeyScanArguments::parse_fields(&response),
+            Err(CamasError::UnexpectedReply(_))
+        ));
+    }
+}
@@ -0,0 +1,31 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct SelectArguments {
+    index: u32,
+}
+
+impl SelectArguments {
+    pub fn new(index: u32) -> Self {
+        Self { index }
+    }
+}
+
+impl CommandArguments for SelectArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![ProtocolDataType::BulkString(self.index.to_string())]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = SelectArguments::new(3).to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("3".into())]);
+    }
+}
@@ -1,6 +1,12 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
 use derive_builder::Builder;
 
-use crate::{data_type::DataType, protocol::ProtocolDataType};
+use crate::{data_type::DataType, error::CamasError, protocol::ProtocolDataType};
 
 use super::{CommandArguments, ProtocolCommandArguments};
 
@@ -13,6 +19,45 @@ pub enum ExpirationTime {
     KeepTTL,
 }
 
+impl ExpirationTime {
+    /// Builds an absolute expiration time out of a point in time.
+    ///
+    /// Uses `EXAT` when the time is a whole second and `PXAT` otherwise, so
+    /// no precision is lost. Times before the Unix epoch become a zero
+    /// timestamp, which [`SetOptions::validate`] rejects.
+    pub fn from_system_time(time: SystemTime) -> Self {
+        let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+
+        if since_epoch.subsec_millis() == 0 {
+            ExpirationTime::TimestampSeconds(since_epoch.as_secs())
+        } else {
+            ExpirationTime::TimestampMilliseconds(since_epoch.as_millis() as u64)
+        }
+    }
+
+    pub(crate) fn to_protocol_arguments(self) -> ProtocolCommandArguments {
+        match self {
+            ExpirationTime::Seconds(seconds) => vec![
+                ProtocolDataType::BulkString("EX".into()),
+                ProtocolDataType::BulkString(seconds.to_string()),
+            ],
+            ExpirationTime::Milliseconds(milliseconds) => vec![
+                ProtocolDataType::BulkString("PX".into()),
+                ProtocolDataType::BulkString(milliseconds.to_string()),
+            ],
+            ExpirationTime::TimestampSeconds(seconds) => vec![
+                ProtocolDataType::BulkString("EXAT".into()),
+                ProtocolDataType::BulkString(seconds.to_string()),
+            ],
+            ExpirationTime::TimestampMilliseconds(milliseconds) => vec![
+                ProtocolDataType::BulkString("PXAT".into()),
+                ProtocolDataType::BulkString(milliseconds.to_string()),
+            ],
+            ExpirationTime::KeepTTL => vec![ProtocolDataType::BulkString("KEEPTTL".into())],
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub enum SetMode {
     SetIfExists,
@@ -28,10 +73,51 @@ pub struct SetOptions {
     pub get_previous_value: bool,
 }
 
+impl SetOptions {
+    /// Checks for option combinations Redis would reject, so they fail
+    /// before being sent.
+    pub fn validate(&self) -> Result<(), SetOptionsError> {
+        match self.expiration_time {
+            Some(ExpirationTime::Seconds(0) | ExpirationTime::Milliseconds(0)) => {
+                Err(SetOptionsError::ZeroExpirationTime)
+            }
+            Some(
+                ExpirationTime::TimestampSeconds(0) | ExpirationTime::TimestampMilliseconds(0),
+            ) => Err(SetOptionsError::ZeroTimestamp),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A combination of [`SetOptions`] that Redis rejects
+#[derive(Debug, PartialEq, Eq)]
+pub enum SetOptionsError {
+    /// `EX` and `PX` must be positive
+    ZeroExpirationTime,
+    /// `EXAT` and `PXAT` must be positive. Past timestamps are fine, since
+    /// the key just expires right away
+    ZeroTimestamp,
+}
+
+impl Display for SetOptionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SetOptionsError::ZeroExpirationTime => {
+                f.write_str("The expiration time must be greater than zero")
+            }
+            SetOptionsError::ZeroTimestamp => {
+                f.write_str("The expiration timestamp must be after the Unix epoch")
+            }
+        }
+    }
+}
+
+impl Error for SetOptionsError {}
+
 #[derive(Clone)]
-pub(crate) struct SetArguments {
+pub struct SetArguments {
     key: String,
-    value: String,
+    value: ProtocolDataType,
     options: SetOptions,
 }
 
@@ -43,7 +129,15 @@ impl SetArguments {
     {
         Self {
             key: key.to_string(),
-            value: value.to_string(),
+            value: ProtocolDataType::BulkString(value.to_string()),
+            options,
+        }
+    }
+
+    pub fn with_bytes<K: ToString>(key: K, value: &[u8], options: SetOptions) -> Self {
+        Self {
+            key: key.to_string(),
+            value: ProtocolDataType::BulkBytes(value.to_vec()),
             options,
         }
     }
@@ -53,7 +147,7 @@ impl CommandArguments for SetArguments {
     fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
         let mut arguments = vec![
             ProtocolDataType::BulkString(self.key.clone()),
-            ProtocolDataType::BulkString(self.value.clone()),
+            self.value.clone(),
         ];
 
         if let Some(set_mode) = &self.options.set_mode {
@@ -72,62 +166,59 @@ impl CommandArguments for SetArguments {
         }
 
         if let Some(expiration_time) = &self.options.expiration_time {
-            match expiration_time {
-                ExpirationTime::Seconds(seconds) => {
-                    arguments.push(ProtocolDataType::BulkString("EX".into()));
-                    arguments.push(ProtocolDataType::BulkString((*seconds).to_string()));
-                }
-                ExpirationTime::Milliseconds(milliseconds) => {
-                    arguments.push(ProtocolDataType::BulkString("PX".into()));
-                    arguments.push(ProtocolDataType::BulkString((*milliseconds).to_string()));
-                }
-                ExpirationTime::TimestampSeconds(seconds) => {
-                    arguments.push(ProtocolDataType::BulkString("EXAT".into()));
-                    arguments.push(ProtocolDataType::BulkString((*seconds).to_string()));
-                }
-                ExpirationTime::TimestampMilliseconds(milliseconds) => {
-                    arguments.push(ProtocolDataType::BulkString("PXAT".into()));
-                    arguments.push(ProtocolDataType::BulkString((*milliseconds).to_string()));
-                }
-                ExpirationTime::KeepTTL => {
-                    arguments.push(ProtocolDataType::BulkString("KEEPTTL".into()));
-                }
-            }
+            arguments.extend(expiration_time.to_protocol_arguments());
         }
 
         arguments
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq)]
 pub enum SetResponse {
     Ok,
     Aborted,
     PreviousValue(Option<DataType>),
+    /// Reply of a `SET` with both `GET` and `NX` or `XX`, where the previous
+    /// value alone doesn't tell whether the new one was written
+    ConditionalPreviousValue {
+        previous_value: Option<DataType>,
+        written: bool,
+    },
 }
 
 impl SetResponse {
-    pub(crate) fn parse(arguments: &SetArguments, response: &ProtocolDataType) -> Self {
+    pub(crate) fn parse(
+        arguments: &SetArguments,
+        response: &ProtocolDataType,
+    ) -> Result<Self, CamasError> {
         if arguments.options.get_previous_value {
-            return match response {
-                ProtocolDataType::Null => SetResponse::PreviousValue(None),
-                value => SetResponse::PreviousValue(Some(value.try_into().unwrap())),
+            let previous_value = match response {
+                ProtocolDataType::Null => None,
+                value => Some(value.try_into()?),
             };
-        }
 
-        if arguments.options.set_mode.is_some() {
-            if let ProtocolDataType::Null = response {
-                return SetResponse::Aborted;
-            }
+            // NX only writes when there was no previous value, while XX only
+            // writes when there was one
+            return Ok(match arguments.options.set_mode {
+                Some(SetMode::SetIfNotExists) => SetResponse::ConditionalPreviousValue {
+                    written: previous_value.is_none(),
+                    previous_value,
+                },
+                Some(SetMode::SetIfExists) => SetResponse::ConditionalPreviousValue {
+                    written: previous_value.is_some(),
+                    previous_value,
+                },
+                None => SetResponse::PreviousValue(previous_value),
+            });
         }
 
-        if let ProtocolDataType::SimpleString(string) = response {
-            if string == "OK" {
-                return SetResponse::Ok;
+        match response {
+            ProtocolDataType::Null if arguments.options.set_mode.is_some() => {
+                Ok(SetResponse::Aborted)
             }
+            ProtocolDataType::SimpleString(string) if string == "OK" => Ok(SetResponse::Ok),
+            _ => Err(CamasError::UnexpectedReply(response.clone())),
         }
-
-        unreachable!("Redis should never return something different here")
     }
 }
 
@@ -273,7 +364,7 @@ impl SetResponse {
 
 //         let arguments = SetArguments::new("foo", "bar", set_options);
 
-//         let result = SetResponse::parse(&arguments, &ProtocolDataType::Null);
+//         let result = SetResponse::parse(&arguments, &ProtocolDataType::Null)?;
 
 //         let expected = SetResponse::PreviousValue(None);
 
@@ -288,7 +379,7 @@ impl SetResponse {
 
 //         let arguments = SetArguments::new("foo", "bar", set_options);
 
-//         let result = SetResponse::parse(&arguments, &ProtocolDataType::BulkString("baz".into()));
+//         let result = SetResponse::parse(&arguments, &ProtocolDataType::BulkString("baz".into()))?;
 
 //         let expected = SetResponse::PreviousValue(Some(DataType::String("baz".into())));
 
@@ -303,7 +394,7 @@ impl SetResponse {
 
 //         let arguments = SetArguments::new("foo", "bar", set_options);
 
-//         let result = SetResponse::parse(&arguments, &ProtocolDataType::Null);
+//         let result = SetResponse::parse(&arguments, &ProtocolDataType::Null)?;
 
 //         let expected = SetResponse::Aborted;
 
@@ -318,10 +409,213 @@ impl SetResponse {
 
 //         let arguments = SetArguments::new("foo", "bar", set_options);
 
-//         let result = SetResponse::parse(&arguments, &ProtocolDataType::Null);
+//         let result = SetResponse::parse(&arguments, &ProtocolDataType::Null)?;
 
 //         let expected = SetResponse::Aborted;
 
 //         assert_eq!(expected, result);
 //     }
 // }
+
+#[cfg(test)]
+mod validation {
+    use super::*;
+
+    #[test]
+    fn accepts_default_options() {
+        assert_eq!(Ok(()), SetOptions::default().validate());
+    }
+
+    #[test]
+    fn accepts_keep_ttl_with_get_and_set_mode() {
+        let options = SetOptions {
+            expiration_time: Some(ExpirationTime::KeepTTL),
+            set_mode: Some(SetMode::SetIfExists),
+            get_previous_value: true,
+        };
+
+        assert_eq!(Ok(()), options.validate());
+    }
+
+    #[test]
+    fn rejects_zero_seconds() {
+        let options = SetOptions {
+            expiration_time: Some(ExpirationTime::Seconds(0)),
+            ..Default::default()
+        };
+
+        assert_eq!(Err(SetOptionsError::ZeroExpirationTime), options.validate());
+    }
+
+    #[test]
+    fn rejects_zero_milliseconds() {
+        let options = SetOptions {
+            expiration_time: Some(ExpirationTime::Milliseconds(0)),
+            ..Default::default()
+        };
+
+        assert_eq!(Err(SetOptionsError::ZeroExpirationTime), options.validate());
+    }
+
+    #[test]
+    fn rejects_zero_timestamp() {
+        let options = SetOptions {
+            expiration_time: Some(ExpirationTime::TimestampSeconds(0)),
+            ..Default::default()
+        };
+
+        assert_eq!(Err(SetOptionsError::ZeroTimestamp), options.validate());
+    }
+
+    #[test]
+    fn accepts_past_timestamp() {
+        let options = SetOptions {
+            expiration_time: Some(ExpirationTime::TimestampMilliseconds(1)),
+            ..Default::default()
+        };
+
+        assert_eq!(Ok(()), options.validate());
+    }
+}
+
+#[cfg(test)]
+mod system_time_conversion {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn converts_whole_second_into_timestamp_seconds() {
+        let time = UNIX_EPOCH + Duration::from_secs(1712451584);
+
+        let result = ExpirationTime::from_system_time(time).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("EXAT".into()),
+                ProtocolDataType::BulkString("1712451584".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn converts_fractional_second_into_timestamp_milliseconds() {
+        let time = UNIX_EPOCH + Duration::from_millis(1712451584250);
+
+        let result = ExpirationTime::from_system_time(time).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("PXAT".into()),
+                ProtocolDataType::BulkString("1712451584250".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn converts_time_before_epoch_into_invalid_timestamp() {
+        let time = UNIX_EPOCH - Duration::from_secs(1);
+
+        let options = SetOptions {
+            expiration_time: Some(ExpirationTime::from_system_time(time)),
+            ..Default::default()
+        };
+
+        assert_eq!(Err(SetOptionsError::ZeroTimestamp), options.validate());
+    }
+}
+
+#[cfg(test)]
+mod response {
+    use std::error::Error;
+
+    use super::*;
+
+    fn arguments_with(set_mode: SetMode) -> SetArguments {
+        let options = SetOptions {
+            set_mode: Some(set_mode),
+            get_previous_value: true,
+            ..Default::default()
+        };
+
+        SetArguments::new("foo", "bar", options)
+    }
+
+    #[test]
+    fn parses_nx_get_with_existing_key_as_not_written() -> Result<(), Box<dyn Error>> {
+        let arguments = arguments_with(SetMode::SetIfNotExists);
+
+        let result = SetResponse::parse(&arguments, &ProtocolDataType::BulkString("baz".into()))?;
+
+        let expected = SetResponse::ConditionalPreviousValue {
+            previous_value: Some(DataType::String("baz".into())),
+            written: false,
+        };
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_nx_get_with_missing_key_as_written() -> Result<(), Box<dyn Error>> {
+        let arguments = arguments_with(SetMode::SetIfNotExists);
+
+        let result = SetResponse::parse(&arguments, &ProtocolDataType::Null)?;
+
+        let expected = SetResponse::ConditionalPreviousValue {
+            previous_value: None,
+            written: true,
+        };
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_xx_get_with_existing_key_as_written() -> Result<(), Box<dyn Error>> {
+        let arguments = arguments_with(SetMode::SetIfExists);
+
+        let result = SetResponse::parse(&arguments, &ProtocolDataType::BulkString("baz".into()))?;
+
+        let expected = SetResponse::ConditionalPreviousValue {
+            previous_value: Some(DataType::String("baz".into())),
+            written: true,
+        };
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_xx_get_with_missing_key_as_not_written() -> Result<(), Box<dyn Error>> {
+        let arguments = arguments_with(SetMode::SetIfExists);
+
+        let result = SetResponse::parse(&arguments, &ProtocolDataType::Null)?;
+
+        let expected = SetResponse::ConditionalPreviousValue {
+            previous_value: None,
+            written: false,
+        };
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_unexpected_reply() {
+        let arguments = SetArguments::new("foo", "bar", SetOptions::default());
+
+        let result = SetResponse::parse(&arguments, &ProtocolDataType::Integer(1));
+
+        assert!(matches!(
+            result,
+            Err(CamasError::UnexpectedReply(ProtocolDataType::Integer(1)))
+        ));
+    }
+}
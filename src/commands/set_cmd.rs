@@ -0,0 +1,192 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct SAddArguments {
+    key: String,
+    members: Vec<String>,
+}
+
+impl SAddArguments {
+    pub fn new<K, M>(key: K, members: &[M]) -> Self
+    where
+        K: ToString,
+        M: ToString,
+    {
+        Self {
+            key: key.to_string(),
+            members: members.iter().map(|member| member.to_string()).collect(),
+        }
+    }
+}
+
+impl CommandArguments for SAddArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut arguments = vec![ProtocolDataType::BulkString(self.key.clone())];
+
+        arguments.extend(
+            self.members
+                .iter()
+                .cloned()
+                .map(ProtocolDataType::BulkString),
+        );
+
+        arguments
+    }
+}
+
+pub struct SMembersArguments {
+    key: String,
+}
+
+impl SMembersArguments {
+    pub fn new<K: ToString>(key: K) -> Self {
+        Self {
+            key: key.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for SMembersArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![ProtocolDataType::BulkString(self.key.clone())]
+    }
+}
+
+pub struct SIsMemberArguments {
+    key: String,
+    member: String,
+}
+
+impl SIsMemberArguments {
+    pub fn new<K, M>(key: K, member: M) -> Self
+    where
+        K: ToString,
+        M: ToString,
+    {
+        Self {
+            key: key.to_string(),
+            member: member.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for SIsMemberArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.member.clone()),
+        ]
+    }
+}
+
+pub struct SCardArguments {
+    key: String,
+}
+
+impl SCardArguments {
+    pub fn new<K: ToString>(key: K) -> Self {
+        Self {
+            key: key.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for SCardArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![ProtocolDataType::BulkString(self.key.clone())]
+    }
+}
+
+pub struct SInterArguments {
+    keys: Vec<String>,
+}
+
+impl SInterArguments {
+    pub fn new<K: ToString>(keys: &[K]) -> Self {
+        Self {
+            keys: keys.iter().map(|key| key.to_string()).collect(),
+        }
+    }
+}
+
+impl CommandArguments for SInterArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        self.keys
+            .iter()
+            .cloned()
+            .map(ProtocolDataType::BulkString)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use crate::commands::Command;
+
+    use super::*;
+
+    #[test]
+    fn builds_sadd_correctly() {
+        let result = SAddArguments::new("set", &["foo", "bar"]).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("set".into()),
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("bar".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_smembers_correctly() {
+        let result = SMembersArguments::new("set").to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("set".into())]);
+    }
+
+    #[test]
+    fn builds_sismember_correctly() {
+        let result = SIsMemberArguments::new("set", "foo").to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("set".into()),
+                ProtocolDataType::BulkString("foo".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_scard_correctly() {
+        let result = SCardArguments::new("set").to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("set".into())]);
+    }
+
+    #[test]
+    fn serializes_smismember_correctly() {
+        let command = Command::SMIsMember(SAddArguments::new("set", &["foo", "bar"]));
+
+        assert_eq!(
+            command.serialize(),
+            b"*4\r\n$10\r\nSMISMEMBER\r\n$3\r\nset\r\n$3\r\nfoo\r\n$3\r\nbar\r\n"
+        );
+    }
+
+    #[test]
+    fn builds_sinter_correctly() {
+        let result = SInterArguments::new(&["foo", "bar"]).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("bar".into()),
+            ]
+        );
+    }
+}
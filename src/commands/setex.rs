@@ -0,0 +1,61 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct SetExArguments {
+    key: String,
+    /// In seconds for `SETEX` and in milliseconds for `PSETEX`
+    time: u64,
+    value: String,
+}
+
+impl SetExArguments {
+    pub fn new<K, V>(key: K, time: u64, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        Self {
+            key: key.to_string(),
+            time,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for SetExArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.time.to_string()),
+            ProtocolDataType::BulkString(self.value.clone()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use crate::commands::Command;
+
+    use super::*;
+
+    #[test]
+    fn serializes_setex_correctly() {
+        let command = Command::SetEx(SetExArguments::new("foo", 10, "bar"));
+
+        assert_eq!(
+            command.serialize(),
+            b"*4\r\n$5\r\nSETEX\r\n$3\r\nfoo\r\n$2\r\n10\r\n$3\r\nbar\r\n"
+        );
+    }
+
+    #[test]
+    fn serializes_psetex_correctly() {
+        let command = Command::PSetEx(SetExArguments::new("foo", 1500, "bar"));
+
+        assert_eq!(
+            command.serialize(),
+            b"*4\r\n$6\r\nPSETEX\r\n$3\r\nfoo\r\n$4\r\n1500\r\n$3\r\nbar\r\n"
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct SubscribeArguments {
+    channels: Vec<String>,
+}
+
+impl SubscribeArguments {
+    pub fn new<C: ToString>(channels: &[C]) -> Self {
+        Self {
+            channels: channels.iter().map(|channel| channel.to_string()).collect(),
+        }
+    }
+}
+
+impl CommandArguments for SubscribeArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        self.channels
+            .iter()
+            .cloned()
+            .map(ProtocolDataType::BulkString)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = SubscribeArguments::new(&["news", "sports"]).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("news".into()),
+                ProtocolDataType::BulkString("sports".into()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{error::CamasError, from_redis_value::FromRedisValue, protocol::ProtocolDataType};
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+#[derive(Default)]
+pub struct TimeArguments;
+
+impl TimeArguments {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Converts the reply of `TIME` into seconds and microseconds since the
+    /// Unix epoch.
+    ///
+    /// Redis sends both as bulk strings in a two-element array.
+    pub(crate) fn parse_response(response: &ProtocolDataType) -> Result<(u64, u64), CamasError> {
+        match response {
+            ProtocolDataType::Array(items) => match items.as_slice() {
+                [seconds, microseconds] => Ok((
+                    i64::from_redis_value(seconds)? as u64,
+                    i64::from_redis_value(microseconds)? as u64,
+                )),
+                _ => Err(CamasError::UnexpectedReply(response.clone())),
+            },
+            _ => Err(CamasError::UnexpectedReply(response.clone())),
+        }
+    }
+}
+
+impl CommandArguments for TimeArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = TimeArguments::new().to_protocol_arguments();
+
+        assert!(result.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod response {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn parses_seconds_and_microseconds() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("1714000000".into()),
+            ProtocolDataType::BulkString("123456".into()),
+        ]);
+
+        let result = TimeArguments::parse_response(&response)?;
+
+        assert_eq!((1714000000, 123456), result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_array_with_wrong_length() {
+        let response =
+            ProtocolDataType::Array(vec![ProtocolDataType::BulkString("1714000000".into())]);
+
+        let result = TimeArguments::parse_response(&response);
+
+        assert!(matches!(result, Err(CamasError::UnexpectedReply(_))));
+    }
+}
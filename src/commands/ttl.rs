@@ -0,0 +1,104 @@
+use crate::{error::CamasError, protocol::ProtocolDataType};
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct TtlArguments {
+    key: String,
+}
+
+impl TtlArguments {
+    pub fn new<K: ToString>(key: K) -> Self {
+        Self {
+            key: key.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for TtlArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![ProtocolDataType::BulkString(self.key.clone())]
+    }
+}
+
+/// The remaining time to live of a key.
+///
+/// The unit of `Expires` depends on the command that was sent: seconds for
+/// `TTL` and milliseconds for `PTTL`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TtlResult {
+    Expires(u64),
+    NoExpiry,
+    NoKey,
+}
+
+impl TtlResult {
+    pub(crate) fn parse(response: &ProtocolDataType) -> Result<Self, CamasError> {
+        match response {
+            ProtocolDataType::Integer(-2) => Ok(TtlResult::NoKey),
+            ProtocolDataType::Integer(-1) => Ok(TtlResult::NoExpiry),
+            ProtocolDataType::Integer(ttl) if *ttl >= 0 => Ok(TtlResult::Expires(*ttl as u64)),
+            _ => Err(CamasError::UnexpectedReply(response.clone())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = TtlArguments::new("foo").to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("foo".into())]);
+    }
+}
+
+#[cfg(test)]
+mod response {
+    use super::*;
+
+    #[test]
+    fn parses_to_no_key_when_got_minus_two() -> Result<(), CamasError> {
+        let result = TtlResult::parse(&ProtocolDataType::Integer(-2))?;
+
+        assert_eq!(result, TtlResult::NoKey);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_to_no_expiry_when_got_minus_one() -> Result<(), CamasError> {
+        let result = TtlResult::parse(&ProtocolDataType::Integer(-1))?;
+
+        assert_eq!(result, TtlResult::NoExpiry);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_to_expires_when_got_positive_value() -> Result<(), CamasError> {
+        let result = TtlResult::parse(&ProtocolDataType::Integer(42))?;
+
+        assert_eq!(result, TtlResult::Expires(42));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_to_expires_when_got_zero() -> Result<(), CamasError> {
+        let result = TtlResult::parse(&ProtocolDataType::Integer(0))?;
+
+        assert_eq!(result, TtlResult::Expires(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_on_unexpected_reply() {
+        assert!(matches!(
+            TtlResult::parse(&ProtocolDataType::Integer(-3)),
+            Err(CamasError::UnexpectedReply(_))
+        ));
+    }
+}
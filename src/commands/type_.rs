@@ -0,0 +1,160 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{error::CamasError, protocol::ProtocolDataType};
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+/// The type of the value stored at a key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyType {
+    None,
+    String,
+    List,
+    Set,
+    ZSet,
+    Hash,
+    Stream,
+}
+
+impl FromStr for KeyType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "none" => Ok(KeyType::None),
+            "string" => Ok(KeyType::String),
+            "list" => Ok(KeyType::List),
+            "set" => Ok(KeyType::Set),
+            "zset" => Ok(KeyType::ZSet),
+            "hash" => Ok(KeyType::Hash),
+            "stream" => Ok(KeyType::Stream),
+            _ => Err(format!("Unknown key type: {value}")),
+        }
+    }
+}
+
+impl Display for KeyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            KeyType::None => "none",
+            KeyType::String => "string",
+            KeyType::List => "list",
+            KeyType::Set => "set",
+            KeyType::ZSet => "zset",
+            KeyType::Hash => "hash",
+            KeyType::Stream => "stream",
+        })
+    }
+}
+
+impl KeyType {
+    pub(crate) fn parse(response: &ProtocolDataType) -> Result<Self, CamasError> {
+        if let ProtocolDataType::SimpleString(key_type) = response {
+            key_type.parse().map_err(CamasError::Conversion)
+        } else {
+            Err(CamasError::UnexpectedReply(response.clone()))
+        }
+    }
+}
+
+pub struct TypeArguments {
+    key: String,
+}
+
+impl TypeArguments {
+    pub fn new<K: ToString>(key: K) -> Self {
+        Self {
+            key: key.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for TypeArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![ProtocolDataType::BulkString(self.key.clone())]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = TypeArguments::new("foo").to_protocol_arguments();
+
+        assert_eq!(result, vec![ProtocolDataType::BulkString("foo".into())]);
+    }
+}
+
+#[cfg(test)]
+mod response {
+    use std::error::Error;
+
+    use super::*;
+
+    fn parse(reply: &str) -> Result<KeyType, CamasError> {
+        KeyType::parse(&ProtocolDataType::SimpleString(reply.into()))
+    }
+
+    #[test]
+    fn parses_none() -> Result<(), Box<dyn Error>> {
+        assert_eq!(parse("none")?, KeyType::None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_string() -> Result<(), Box<dyn Error>> {
+        assert_eq!(parse("string")?, KeyType::String);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_list() -> Result<(), Box<dyn Error>> {
+        assert_eq!(parse("list")?, KeyType::List);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_set() -> Result<(), Box<dyn Error>> {
+        assert_eq!(parse("set")?, KeyType::Set);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_zset() -> Result<(), Box<dyn Error>> {
+        assert_eq!(parse("zset")?, KeyType::ZSet);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_hash() -> Result<(), Box<dyn Error>> {
+        assert_eq!(parse("hash")?, KeyType::Hash);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_stream() -> Result<(), Box<dyn Error>> {
+        assert_eq!(parse("stream")?, KeyType::Stream);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_unknown_type() {
+        assert!(parse("ReJSON-RL").is_err());
+    }
+
+    #[test]
+    fn fails_on_non_simple_string_reply() {
+        let result = KeyType::parse(&ProtocolDataType::Integer(1));
+
+        assert!(matches!(result, Err(CamasError::UnexpectedReply(_))));
+    }
+}
@@ -0,0 +1,44 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct UnlinkArguments {
+    keys: Vec<String>,
+}
+
+impl UnlinkArguments {
+    pub fn new<K: ToString>(keys: Vec<K>) -> Self {
+        Self {
+            keys: keys.iter().map(|item| item.to_string()).collect(),
+        }
+    }
+}
+
+impl CommandArguments for UnlinkArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        self.keys
+            .iter()
+            .cloned()
+            .map(ProtocolDataType::BulkString)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = UnlinkArguments::new(vec!["foo", "bar", "baz"]).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("bar".into()),
+                ProtocolDataType::BulkString("baz".into())
+            ]
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use crate::protocol::ProtocolDataType;
+
+use super::{CommandArguments, ProtocolCommandArguments};
+
+pub struct WatchArguments {
+    keys: Vec<String>,
+}
+
+impl WatchArguments {
+    pub fn new<K: ToString>(keys: &[K]) -> Self {
+        Self {
+            keys: keys.iter().map(|key| key.to_string()).collect(),
+        }
+    }
+}
+
+impl CommandArguments for WatchArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        self.keys
+            .iter()
+            .cloned()
+            .map(ProtocolDataType::BulkString)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use super::*;
+
+    #[test]
+    fn builds_correctly() {
+        let result = WatchArguments::new(&["foo", "bar"]).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("foo".into()),
+                ProtocolDataType::BulkString("bar".into()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use crate::{error::CamasError, from_redis_value::FromRedisValue, protocol::ProtocolDataType};
+
+use super::{parse_string, CommandArguments, ProtocolCommandArguments};
+
+pub struct ZAddArguments {
+    key: String,
+    pairs: Vec<(f64, String)>,
+}
+
+impl ZAddArguments {
+    pub fn new<K, M>(key: K, pairs: &[(f64, M)]) -> Self
+    where
+        K: ToString,
+        M: ToString,
+    {
+        Self {
+            key: key.to_string(),
+            pairs: pairs
+                .iter()
+                .map(|(score, member)| (*score, member.to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl CommandArguments for ZAddArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut arguments = vec![ProtocolDataType::BulkString(self.key.clone())];
+
+        for (score, member) in &self.pairs {
+            arguments.push(ProtocolDataType::BulkString(score.to_string()));
+            arguments.push(ProtocolDataType::BulkString(member.clone()));
+        }
+
+        arguments
+    }
+}
+
+pub struct ZRangeArguments {
+    key: String,
+    start: i64,
+    stop: i64,
+    with_scores: bool,
+}
+
+impl ZRangeArguments {
+    pub fn new<K: ToString>(key: K, start: i64, stop: i64, with_scores: bool) -> Self {
+        Self {
+            key: key.to_string(),
+            start,
+            stop,
+            with_scores,
+        }
+    }
+
+    /// Converts the reply of `ZRANGE` into members paired with their scores,
+    /// which are only present if they were requested.
+    ///
+    /// Under RESP2, members and scores are interleaved in a flat array, while
+    /// under RESP3 each member comes in an array along with its score.
+    /// Members that aren't valid UTF-8 are decoded like [`parse_string`]
+    /// does.
+    pub(crate) fn parse_response(
+        response: &ProtocolDataType,
+        with_scores: bool,
+    ) -> Result<Vec<(String, Option<f64>)>, CamasError> {
+        let ProtocolDataType::Array(items) = response else {
+            return Err(CamasError::UnexpectedReply(response.clone()));
+        };
+
+        if !with_scores {
+            return items
+                .iter()
+                .map(|member| Ok((parse_string(member)?, None)))
+                .collect();
+        }
+
+        let parse_pair = |member, score| -> Result<_, CamasError> {
+            Ok((parse_string(member)?, Some(f64::from_redis_value(score)?)))
+        };
+
+        match items.first() {
+            Some(ProtocolDataType::Array(_)) => items
+                .iter()
+                .map(|pair| match pair {
+                    ProtocolDataType::Array(pair) if pair.len() == 2 => {
+                        parse_pair(&pair[0], &pair[1])
+                    }
+                    _ => Err(CamasError::UnexpectedReply(pair.clone())),
+                })
+                .collect(),
+            _ => items
+                .chunks(2)
+                .map(|pair| match pair {
+                    [member, score] => parse_pair(member, score),
+                    _ => Err(CamasError::UnexpectedReply(response.clone())),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl CommandArguments for ZRangeArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        let mut arguments = vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.start.to_string()),
+            ProtocolDataType::BulkString(self.stop.to_string()),
+        ];
+
+        if self.with_scores {
+            arguments.push(ProtocolDataType::BulkString("WITHSCORES".into()));
+        }
+
+        arguments
+    }
+}
+
+pub struct ZScoreArguments {
+    key: String,
+    member: String,
+}
+
+impl ZScoreArguments {
+    pub fn new<K, M>(key: K, member: M) -> Self
+    where
+        K: ToString,
+        M: ToString,
+    {
+        Self {
+            key: key.to_string(),
+            member: member.to_string(),
+        }
+    }
+}
+
+impl CommandArguments for ZScoreArguments {
+    fn to_protocol_arguments(&self) -> ProtocolCommandArguments {
+        vec![
+            ProtocolDataType::BulkString(self.key.clone()),
+            ProtocolDataType::BulkString(self.member.clone()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod protocol_arguments {
+    use crate::commands::Command;
+
+    use super::*;
+
+    #[test]
+    fn builds_zadd_correctly() {
+        let result = ZAddArguments::new("zset", &[(1.5, "a"), (2.0, "b")]).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("zset".into()),
+                ProtocolDataType::BulkString("1.5".into()),
+                ProtocolDataType::BulkString("a".into()),
+                ProtocolDataType::BulkString("2".into()),
+                ProtocolDataType::BulkString("b".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn serializes_zadd_correctly() {
+        let command = Command::ZAdd(ZAddArguments::new("zset", &[(1.5, "a"), (2.0, "b")]));
+
+        assert_eq!(
+            command.serialize(),
+            b"*6\r\n$4\r\nZADD\r\n$4\r\nzset\r\n$3\r\n1.5\r\n$1\r\na\r\n$1\r\n2\r\n$1\r\nb\r\n"
+        );
+    }
+
+    #[test]
+    fn builds_zrange_correctly() {
+        let result = ZRangeArguments::new("zset", 0, -1, false).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("zset".into()),
+                ProtocolDataType::BulkString("0".into()),
+                ProtocolDataType::BulkString("-1".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_zrange_with_scores_correctly() {
+        let result = ZRangeArguments::new("zset", 0, -1, true).to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("zset".into()),
+                ProtocolDataType::BulkString("0".into()),
+                ProtocolDataType::BulkString("-1".into()),
+                ProtocolDataType::BulkString("WITHSCORES".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn builds_zscore_correctly() {
+        let result = ZScoreArguments::new("zset", "a").to_protocol_arguments();
+
+        assert_eq!(
+            result,
+            vec![
+                ProtocolDataType::BulkString("zset".into()),
+                ProtocolDataType::BulkString("a".into()),
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod response {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn parses_members_without_scores() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("a".into()),
+            ProtocolDataType::BulkString("b".into()),
+        ]);
+
+        let result = ZRangeArguments::parse_response(&response, false)?;
+
+        assert_eq!(result, vec![("a".into(), None), ("b".into(), None)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_binary_members_lossily() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkBytes(vec![b'a', 0xff]),
+            ProtocolDataType::Double(1.5),
+        ]);
+
+        let result = ZRangeArguments::parse_response(&response, true)?;
+
+        assert_eq!(result, vec![("a\u{fffd}".into(), Some(1.5))]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_interleaved_members_and_scores() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("a".into()),
+            ProtocolDataType::BulkString("1.5".into()),
+            ProtocolDataType::BulkString("b".into()),
+            ProtocolDataType::BulkString("2".into()),
+        ]);
+
+        let result = ZRangeArguments::parse_response(&response, true)?;
+
+        assert_eq!(
+            result,
+            vec![("a".into(), Some(1.5)), ("b".into(), Some(2.0))]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_paired_members_and_scores() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(vec![
+            ProtocolDataType::Array(vec![
+                ProtocolDataType::BulkString("a".into()),
+                ProtocolDataType::Double(1.5),
+            ]),
+            ProtocolDataType::Array(vec![
+                ProtocolDataType::BulkString("b".into()),
+                ProtocolDataType::Double(2.0),
+            ]),
+        ]);
+
+        let result = ZRangeArguments::parse_response(&response, true)?;
+
+        assert_eq!(
+            result,
+            vec![("a".into(), Some(1.5)), ("b".into(), Some(2.0))]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_empty_range() -> Result<(), Box<dyn Error>> {
+        let response = ProtocolDataType::Array(Vec::new());
+
+        let result = ZRangeArguments::parse_response(&response, true)?;
+
+        assert!(result.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_member_without_score() {
+        let response = ProtocolDataType::Array(vec![ProtocolDataType::BulkString("a".into())]);
+
+        let result = ZRangeArguments::parse_response(&response, true);
+
+        assert!(matches!(result, Err(CamasError::UnexpectedReply(_))));
+    }
+
+    #[test]
+    fn fails_on_non_array_reply() {
+        let result = ZRangeArguments::parse_response(&ProtocolDataType::Integer(1), false);
+
+        assert!(matches!(result, Err(CamasError::UnexpectedReply(_))));
+    }
+}
@@ -1,53 +1,149 @@
-use crate::protocol::ProtocolDataType;
+use crate::{error::CamasError, protocol::ProtocolDataType};
 
 use std::fmt::Display;
 
 /// A user-facing Redis data type
-#[derive(Clone, Debug, PartialEq, Eq)]
+///
+/// Replies are parsed into [`ProtocolDataType`], which mirrors RESP, and
+/// then converted into this type.
+///
+/// # Example
+///
+/// ```
+/// use camas::data_type::DataType;
+///
+/// fn describe(value: &DataType) -> &'static str {
+///     match value {
+///         DataType::String(_) => "string",
+///         DataType::Bytes(_) => "bytes",
+///         DataType::List(_) => "list",
+///         DataType::Integer(_) => "integer",
+///         DataType::Double(_) => "double",
+///         DataType::Boolean(_) => "boolean",
+///     }
+/// }
+///
+/// assert_eq!(describe(&DataType::String(String::from("Hello"))), "string");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
 pub enum DataType {
     String(String),
+    /// A string holding arbitrary bytes, which aren't valid UTF-8
+    Bytes(Vec<u8>),
     List(Vec<String>),
+    /// A number Redis replied with as an integer, rather than as a string
+    Integer(i64),
+    /// A number Redis replied with as a double, rather than as a string
+    Double(f64),
+    Boolean(bool),
 }
 
-impl Into<ProtocolDataType> for DataType {
-    fn into(self) -> ProtocolDataType {
+impl DataType {
+    /// Returns the string, if this is one
+    pub fn as_str(&self) -> Option<&str> {
         match self {
+            DataType::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// Returns the owned string, if this is one
+    pub fn into_string(self) -> Option<String> {
+        match self {
+            DataType::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// Returns the items, if this is a list
+    pub fn as_list(&self) -> Option<&[String]> {
+        match self {
+            DataType::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer, if this is one, or parses the string as an
+    /// integer, if this is a string holding one
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            DataType::Integer(integer) => Some(*integer),
+            _ => self.as_str()?.parse().ok(),
+        }
+    }
+
+    /// Returns the number, if this is one, or parses the string as a float,
+    /// if this is a string holding one
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            DataType::Double(double) => Some(*double),
+            DataType::Integer(integer) => Some(*integer as f64),
+            _ => self.as_str()?.parse().ok(),
+        }
+    }
+
+    /// Converts an item of an array reply into an item of [`DataType::List`],
+    /// keeping strings and numbers as they are rather than formatting them
+    /// with [`Display`]
+    fn into_list_item(self) -> String {
+        match self {
+            DataType::String(string) => string,
+            DataType::Bytes(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+            DataType::Integer(integer) => integer.to_string(),
+            DataType::Double(double) => double.to_string(),
+            DataType::Boolean(boolean) => boolean.to_string(),
+            list @ DataType::List(_) => list.to_string(),
+        }
+    }
+}
+
+impl From<DataType> for ProtocolDataType {
+    fn from(value: DataType) -> Self {
+        match value {
             DataType::String(string) => ProtocolDataType::BulkString(string),
+            DataType::Bytes(bytes) => ProtocolDataType::BulkBytes(bytes),
             DataType::List(list) => ProtocolDataType::Array(
                 list.iter()
                     .cloned()
                     .map(ProtocolDataType::BulkString)
                     .collect(),
             ),
+            DataType::Integer(integer) => ProtocolDataType::Integer(integer),
+            DataType::Double(double) => ProtocolDataType::Double(double),
+            DataType::Boolean(boolean) => ProtocolDataType::Boolean(boolean),
         }
     }
 }
 
 impl TryFrom<ProtocolDataType> for DataType {
-    type Error = String;
+    type Error = CamasError;
 
     fn try_from(value: ProtocolDataType) -> Result<Self, Self::Error> {
         match value {
-            ProtocolDataType::Double(double) => Ok(Self::String(double.to_string())),
-            ProtocolDataType::Boolean(boolean) => Ok(Self::String(boolean.to_string())),
-            ProtocolDataType::Integer(integer) => Ok(Self::String(integer.to_string())),
+            ProtocolDataType::Double(double) => Ok(Self::Double(double)),
+            ProtocolDataType::Boolean(boolean) => Ok(Self::Boolean(boolean)),
+            ProtocolDataType::Integer(integer) => Ok(Self::Integer(integer)),
             ProtocolDataType::BigNumber(number) => Ok(Self::String(number.to_string())),
             ProtocolDataType::BulkString(string) => Ok(Self::String(string.to_string())),
+            ProtocolDataType::BulkBytes(bytes) => Ok(Self::Bytes(bytes)),
             ProtocolDataType::SimpleString(string) => Ok(Self::String(string.to_string())),
+            ProtocolDataType::VerbatimString { content, .. } => Ok(Self::String(content)),
             ProtocolDataType::Array(items) => Ok(Self::List(
                 items
-                    .iter()
-                    .cloned()
-                    .map(|item| DataType::try_from(item).unwrap().to_string())
-                    .collect(),
+                    .into_iter()
+                    .map(|item| DataType::try_from(item).map(DataType::into_list_item))
+                    .collect::<Result<_, _>>()?,
             )),
-            _ => Err("sei la".into()),
+            value => Err(CamasError::Conversion(format!(
+                "Can't convert {} into DataType",
+                value.variant_name()
+            ))),
         }
     }
 }
 
 impl TryFrom<&ProtocolDataType> for DataType {
-    type Error = String;
+    type Error = CamasError;
 
     fn try_from(value: &ProtocolDataType) -> Result<Self, Self::Error> {
         value.clone().try_into()
@@ -58,6 +154,7 @@ impl Display for DataType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             DataType::String(string) => f.write_fmt(format_args!("\"{}\"", string)),
+            DataType::Bytes(bytes) => f.write_fmt(format_args!("\"{}\"", bytes.escape_ascii())),
             DataType::List(list) => {
                 let items = list
                     .iter()
@@ -67,6 +164,194 @@ impl Display for DataType {
 
                 f.write_fmt(format_args!("[{}]", items))
             }
+            DataType::Integer(integer) => integer.fmt(f),
+            DataType::Double(double) => double.fmt(f),
+            DataType::Boolean(boolean) => boolean.fmt(f),
         }
     }
 }
+
+#[cfg(test)]
+mod accessors {
+    use super::*;
+
+    #[test]
+    fn returns_str_of_string() {
+        let value = DataType::String("foo".into());
+
+        assert_eq!(Some("foo"), value.as_str());
+    }
+
+    #[test]
+    fn returns_no_str_of_list() {
+        let value = DataType::List(vec!["foo".into()]);
+
+        assert_eq!(None, value.as_str());
+    }
+
+    #[test]
+    fn converts_string_into_owned_string() {
+        let value = DataType::String("foo".into());
+
+        assert_eq!(Some("foo".to_string()), value.into_string());
+    }
+
+    #[test]
+    fn converts_bytes_into_no_string() {
+        let value = DataType::Bytes(vec![0xff]);
+
+        assert_eq!(None, value.into_string());
+    }
+
+    #[test]
+    fn returns_items_of_list() {
+        let value = DataType::List(vec!["foo".into(), "bar".into()]);
+
+        assert_eq!(
+            Some(&["foo".to_string(), "bar".to_string()][..]),
+            value.as_list()
+        );
+    }
+
+    #[test]
+    fn returns_no_items_of_string() {
+        let value = DataType::String("foo".into());
+
+        assert_eq!(None, value.as_list());
+    }
+
+    #[test]
+    fn parses_integer_string_as_i64() {
+        let value = DataType::String("-42".into());
+
+        assert_eq!(Some(-42), value.as_i64());
+    }
+
+    #[test]
+    fn returns_integer_as_i64_and_f64() {
+        let value = DataType::Integer(-42);
+
+        assert_eq!(Some(-42), value.as_i64());
+        assert_eq!(Some(-42.0), value.as_f64());
+    }
+
+    #[test]
+    fn parses_no_i64_out_of_non_integer_string() {
+        let value = DataType::String("4.2".into());
+
+        assert_eq!(None, value.as_i64());
+    }
+
+    #[test]
+    fn parses_float_string_as_f64() {
+        let value = DataType::String("4.5".into());
+
+        assert_eq!(Some(4.5), value.as_f64());
+    }
+
+    #[test]
+    fn parses_no_f64_out_of_non_numeric_string() {
+        let value = DataType::String("foo".into());
+
+        assert_eq!(None, value.as_f64());
+    }
+
+    #[test]
+    fn parses_no_f64_out_of_list() {
+        let value = DataType::List(vec!["4.5".into()]);
+
+        assert_eq!(None, value.as_f64());
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn round_trips_integer() -> Result<(), Box<dyn Error>> {
+        let value = DataType::try_from(ProtocolDataType::Integer(-42))?;
+
+        assert_eq!(DataType::Integer(-42), value);
+        assert_eq!(
+            ProtocolDataType::Integer(-42),
+            ProtocolDataType::from(value)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trips_double() -> Result<(), Box<dyn Error>> {
+        let value = DataType::try_from(ProtocolDataType::Double(4.5))?;
+
+        assert_eq!(DataType::Double(4.5), value);
+        assert_eq!(ProtocolDataType::Double(4.5), ProtocolDataType::from(value));
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trips_boolean() -> Result<(), Box<dyn Error>> {
+        let value = DataType::try_from(ProtocolDataType::Boolean(true))?;
+
+        assert_eq!(DataType::Boolean(true), value);
+        assert_eq!(
+            ProtocolDataType::Boolean(true),
+            ProtocolDataType::from(value)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn keeps_integer_apart_from_numeric_string() -> Result<(), Box<dyn Error>> {
+        let value = DataType::try_from(ProtocolDataType::BulkString("42".into()))?;
+
+        assert_ne!(DataType::Integer(42), value);
+        assert_eq!(Some(42), value.as_i64());
+
+        Ok(())
+    }
+
+    #[test]
+    fn converts_mixed_array_into_list_without_quotes() -> Result<(), Box<dyn Error>> {
+        let value = DataType::try_from(ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("foo".into()),
+            ProtocolDataType::Integer(1),
+            ProtocolDataType::Double(1.5),
+        ]))?;
+
+        assert_eq!(
+            DataType::List(vec!["foo".into(), "1".into(), "1.5".into()]),
+            value
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_convert_simple_error_naming_it() {
+        let result = DataType::try_from(ProtocolDataType::SimpleError("ERR".into()));
+
+        assert_eq!(
+            "Can't convert SimpleError into DataType",
+            result.unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn fails_to_convert_array_with_null_naming_the_item() {
+        let result = DataType::try_from(ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("foo".into()),
+            ProtocolDataType::Null,
+        ]));
+
+        assert_eq!(
+            "Can't convert Null into DataType",
+            result.unwrap_err().to_string()
+        );
+    }
+}
@@ -1,10 +1,101 @@
-use std::error::Error;
+use std::fmt::Write;
 
-use log::debug;
+use log::{debug, log_enabled, Level};
 use owo_colors::OwoColorize;
 
-pub fn log(tag: &str, message: &String) -> Result<(), Box<dyn Error>> {
-    debug!("{} {}: {:?}", "[camas]".yellow(), tag.bold(), message);
+/// Bytes shown on each line of a hexdump
+const HEXDUMP_WIDTH: usize = 16;
 
-    Ok(())
+pub fn log(tag: &str, message: &[u8]) {
+    debug!(
+        "{} {}: {:?}",
+        "[camas]".yellow(),
+        tag.bold(),
+        String::from_utf8_lossy(message)
+    );
+}
+
+/// Logs raw frames as a hexdump when the `CAMAS_HEXDUMP` environment
+/// variable is set, since binary payloads are unreadable otherwise.
+pub fn log_bytes(tag: &str, bytes: &[u8]) {
+    if !log_enabled!(Level::Debug) {
+        return;
+    }
+
+    if std::env::var_os("CAMAS_HEXDUMP").is_none() {
+        return log(tag, bytes);
+    }
+
+    debug!("{} {}:\n{}", "[camas]".yellow(), tag.bold(), hexdump(bytes));
+}
+
+/// Formats bytes as lines of an offset, the bytes in hex and their
+/// printable ASCII characters
+pub(crate) fn hexdump(bytes: &[u8]) -> String {
+    let mut dump = String::new();
+
+    for (line, chunk) in bytes.chunks(HEXDUMP_WIDTH).enumerate() {
+        let hex = chunk
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let ascii = chunk
+            .iter()
+            .map(|&byte| {
+                if byte.is_ascii_graphic() || byte == b' ' {
+                    byte as char
+                } else {
+                    '.'
+                }
+            })
+            .collect::<String>();
+
+        if line > 0 {
+            dump.push('\n');
+        }
+
+        let _ = write!(
+            dump,
+            "{:08x}  {:<width$}  |{}|",
+            line * HEXDUMP_WIDTH,
+            hex,
+            ascii,
+            width = HEXDUMP_WIDTH * 3 - 1
+        );
+    }
+
+    dump
+}
+
+#[cfg(test)]
+mod formatting {
+    use super::*;
+
+    #[test]
+    fn formats_short_slice_in_a_single_line() {
+        let result = hexdump(b"+OK\r\n");
+
+        assert_eq!(
+            "00000000  2b 4f 4b 0d 0a                                   |+OK..|",
+            result
+        );
+    }
+
+    #[test]
+    fn breaks_lines_every_sixteen_bytes() {
+        let result = hexdump(b"$17\r\nHello, World!\xff\x00\r\n");
+
+        assert_eq!(
+            "00000000  24 31 37 0d 0a 48 65 6c 6c 6f 2c 20 57 6f 72 6c  |$17..Hello, Worl|\n\
+             00000010  64 21 ff 00 0d 0a                                |d!....|",
+            result
+        );
+    }
+
+    #[test]
+    fn formats_empty_slice_as_nothing() {
+        assert_eq!("", hexdump(b""));
+    }
 }
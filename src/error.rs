@@ -0,0 +1,132 @@
+use std::{error::Error, fmt::Display};
+
+use crate::{commands::set::SetOptionsError, protocol::ProtocolDataType};
+
+/// An error that happened while talking to Redis
+#[derive(Debug)]
+pub enum CamasError {
+    /// The connection to Redis failed
+    Io(std::io::Error),
+    /// Redis sent data that doesn't follow the protocol
+    Protocol(String),
+    /// Redis replied with an error, such as `WRONGTYPE Operation against a
+    /// key holding the wrong kind of value`
+    Server { code: String, message: String },
+    /// Redis replied with something the command never replies with
+    UnexpectedReply(ProtocolDataType),
+    /// A reply couldn't be converted to the requested type
+    Conversion(String),
+    /// A connection URL is malformed
+    InvalidUrl(String),
+    /// A transaction wasn't executed because a watched key changed
+    TransactionAborted,
+    /// The options given to `SET` can't be combined
+    InvalidSetOptions(SetOptionsError),
+    /// An argument would be rejected by Redis, so the command wasn't sent
+    InvalidArgument(String),
+}
+
+impl CamasError {
+    /// Splits an error reply into its code (its first word, by convention)
+    /// and its message
+    pub(crate) fn from_server_reply(reply: &str) -> Self {
+        let (code, message) = reply.split_once(' ').unwrap_or((reply, ""));
+
+        CamasError::Server {
+            code: code.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for CamasError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CamasError::Io(error) => error.fmt(f),
+            CamasError::Protocol(message) => {
+                f.write_fmt(format_args!("Protocol error: {}", message))
+            }
+            CamasError::Server { code, message } if message.is_empty() => f.write_str(code),
+            CamasError::Server { code, message } => {
+                f.write_fmt(format_args!("{} {}", code, message))
+            }
+            CamasError::UnexpectedReply(reply) => {
+                f.write_fmt(format_args!("Unexpected reply: {}", reply))
+            }
+            CamasError::Conversion(message) => f.write_str(message),
+            CamasError::InvalidUrl(message) => {
+                f.write_fmt(format_args!("Invalid URL: {}", message))
+            }
+            CamasError::TransactionAborted => {
+                f.write_str("Transaction aborted since a watched key changed")
+            }
+            CamasError::InvalidSetOptions(error) => {
+                f.write_fmt(format_args!("Invalid SET options: {}", error))
+            }
+            CamasError::InvalidArgument(message) => {
+                f.write_fmt(format_args!("Invalid argument: {}", message))
+            }
+        }
+    }
+}
+
+impl Error for CamasError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CamasError::Io(error) => Some(error),
+            CamasError::InvalidSetOptions(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for CamasError {
+    fn from(error: std::io::Error) -> Self {
+        CamasError::Io(error)
+    }
+}
+
+impl From<SetOptionsError> for CamasError {
+    fn from(error: SetOptionsError) -> Self {
+        CamasError::InvalidSetOptions(error)
+    }
+}
+
+#[cfg(test)]
+mod server_reply {
+    use super::*;
+
+    #[test]
+    fn splits_code_and_message() {
+        let result = CamasError::from_server_reply(
+            "WRONGTYPE Operation against a key holding the wrong kind of value",
+        );
+
+        assert!(matches!(
+            result,
+            CamasError::Server { code, message }
+                if code == "WRONGTYPE"
+                    && message == "Operation against a key holding the wrong kind of value"
+        ));
+    }
+
+    #[test]
+    fn keeps_whole_reply_as_code_when_there_is_no_message() {
+        let result = CamasError::from_server_reply("NOAUTH");
+
+        assert!(matches!(
+            result,
+            CamasError::Server { code, message } if code == "NOAUTH" && message.is_empty()
+        ));
+    }
+
+    #[test]
+    fn displays_as_the_original_reply() {
+        let result = CamasError::from_server_reply("ERR value is not an integer or out of range");
+
+        assert_eq!(
+            "ERR value is not an integer or out of range",
+            result.to_string()
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{error::CamasError, protocol::ProtocolDataType};
+
+/// A type that can be built out of a Redis reply
+pub trait FromRedisValue: Sized {
+    fn from_redis_value(value: &ProtocolDataType) -> Result<Self, CamasError>;
+}
+
+fn conversion_error(value: &ProtocolDataType, target: &str) -> CamasError {
+    CamasError::Conversion(format!("Can't convert {} into {}", value, target))
+}
+
+impl FromRedisValue for String {
+    fn from_redis_value(value: &ProtocolDataType) -> Result<Self, CamasError> {
+        match value {
+            ProtocolDataType::BulkString(string) | ProtocolDataType::SimpleString(string) => {
+                Ok(string.clone())
+            }
+            ProtocolDataType::VerbatimString { content, .. } => Ok(content.clone()),
+            ProtocolDataType::Integer(integer) => Ok(integer.to_string()),
+            ProtocolDataType::Double(double) => Ok(double.to_string()),
+            ProtocolDataType::BigNumber(number) => Ok(number.to_string()),
+            _ => Err(conversion_error(value, "a string")),
+        }
+    }
+}
+
+impl FromRedisValue for i64 {
+    fn from_redis_value(value: &ProtocolDataType) -> Result<Self, CamasError> {
+        match value {
+            ProtocolDataType::Integer(integer) => Ok(*integer),
+            // Redis stores numbers as strings, so `GET` replies with them
+            ProtocolDataType::BulkString(string) | ProtocolDataType::SimpleString(string) => string
+                .parse()
+                .map_err(|_| conversion_error(value, "an integer")),
+            _ => Err(conversion_error(value, "an integer")),
+        }
+    }
+}
+
+impl FromRedisValue for f64 {
+    fn from_redis_value(value: &ProtocolDataType) -> Result<Self, CamasError> {
+        match value {
+            ProtocolDataType::Double(double) => Ok(*double),
+            ProtocolDataType::Integer(integer) => Ok(*integer as f64),
+            ProtocolDataType::BulkString(string) | ProtocolDataType::SimpleString(string) => string
+                .parse()
+                .map_err(|_| conversion_error(value, "a float")),
+            _ => Err(conversion_error(value, "a float")),
+        }
+    }
+}
+
+impl FromRedisValue for bool {
+    fn from_redis_value(value: &ProtocolDataType) -> Result<Self, CamasError> {
+        match value {
+            ProtocolDataType::Boolean(boolean) => Ok(*boolean),
+            // RESP2 has no booleans, so they're sent as 0 or 1
+            ProtocolDataType::Integer(0) => Ok(false),
+            ProtocolDataType::Integer(1) => Ok(true),
+            _ => Err(conversion_error(value, "a boolean")),
+        }
+    }
+}
+
+impl FromRedisValue for Vec<String> {
+    fn from_redis_value(value: &ProtocolDataType) -> Result<Self, CamasError> {
+        match value {
+            ProtocolDataType::Array(items) | ProtocolDataType::Set(items) => {
+                items.iter().map(String::from_redis_value).collect()
+            }
+            _ => Err(conversion_error(value, "a list of strings")),
+        }
+    }
+}
+
+impl<T: FromRedisValue> FromRedisValue for Option<T> {
+    fn from_redis_value(value: &ProtocolDataType) -> Result<Self, CamasError> {
+        match value {
+            ProtocolDataType::Null => Ok(None),
+            value => T::from_redis_value(value).map(Some),
+        }
+    }
+}
+
+#[cfg(test)]
+mod conversion {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn converts_bulk_string_into_string() -> Result<(), Box<dyn Error>> {
+        let result = String::from_redis_value(&ProtocolDataType::BulkString("foo".into()))?;
+
+        assert_eq!("foo", result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_convert_array_into_string() {
+        let result = String::from_redis_value(&ProtocolDataType::Array(vec![]));
+
+        assert!(matches!(result, Err(CamasError::Conversion(_))));
+    }
+
+    #[test]
+    fn converts_integer_into_i64() -> Result<(), Box<dyn Error>> {
+        let result = i64::from_redis_value(&ProtocolDataType::Integer(-42))?;
+
+        assert_eq!(-42, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn converts_numeric_bulk_string_into_i64() -> Result<(), Box<dyn Error>> {
+        let result = i64::from_redis_value(&ProtocolDataType::BulkString("42".into()))?;
+
+        assert_eq!(42, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_convert_non_numeric_bulk_string_into_i64() {
+        let result = i64::from_redis_value(&ProtocolDataType::BulkString("foo".into()));
+
+        assert!(matches!(result, Err(CamasError::Conversion(_))));
+    }
+
+    #[test]
+    fn converts_double_into_f64() -> Result<(), Box<dyn Error>> {
+        let result = f64::from_redis_value(&ProtocolDataType::Double(4.5))?;
+
+        assert_eq!(4.5, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn converts_numeric_bulk_string_into_f64() -> Result<(), Box<dyn Error>> {
+        let result = f64::from_redis_value(&ProtocolDataType::BulkString("4.5".into()))?;
+
+        assert_eq!(4.5, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_convert_boolean_into_f64() {
+        let result = f64::from_redis_value(&ProtocolDataType::Boolean(true));
+
+        assert!(matches!(result, Err(CamasError::Conversion(_))));
+    }
+
+    #[test]
+    fn converts_boolean_into_bool() -> Result<(), Box<dyn Error>> {
+        let result = bool::from_redis_value(&ProtocolDataType::Boolean(true))?;
+
+        assert!(result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn converts_zero_into_false() -> Result<(), Box<dyn Error>> {
+        let result = bool::from_redis_value(&ProtocolDataType::Integer(0))?;
+
+        assert!(!result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_convert_other_integer_into_bool() {
+        let result = bool::from_redis_value(&ProtocolDataType::Integer(2));
+
+        assert!(matches!(result, Err(CamasError::Conversion(_))));
+    }
+
+    #[test]
+    fn converts_array_into_vec_of_strings() -> Result<(), Box<dyn Error>> {
+        let result = Vec::<String>::from_redis_value(&ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("foo".into()),
+            ProtocolDataType::BulkString("bar".into()),
+        ]))?;
+
+        assert_eq!(vec!["foo".to_string(), "bar".to_string()], result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_convert_array_with_nested_array_into_vec_of_strings() {
+        let result = Vec::<String>::from_redis_value(&ProtocolDataType::Array(vec![
+            ProtocolDataType::Array(vec![]),
+        ]));
+
+        assert!(matches!(result, Err(CamasError::Conversion(_))));
+    }
+
+    #[test]
+    fn converts_null_into_none() -> Result<(), Box<dyn Error>> {
+        let result = Option::<i64>::from_redis_value(&ProtocolDataType::Null)?;
+
+        assert_eq!(None, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn converts_value_into_some() -> Result<(), Box<dyn Error>> {
+        let result = Option::<i64>::from_redis_value(&ProtocolDataType::Integer(1))?;
+
+        assert_eq!(Some(1), result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_convert_null_into_i64() {
+        let result = i64::from_redis_value(&ProtocolDataType::Null);
+
+        assert!(matches!(result, Err(CamasError::Conversion(_))));
+    }
+}
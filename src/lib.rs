@@ -2,4 +2,18 @@ pub mod client;
 pub mod commands;
 pub mod data_type;
 pub(crate) mod debug;
-pub(crate) mod protocol;
+pub mod error;
+pub mod from_redis_value;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod observer;
+pub mod pipeline;
+pub mod pool;
+pub mod protocol;
+pub mod scan;
+pub(crate) mod stream;
+pub mod subscription;
+pub(crate) mod url;
+
+#[cfg(feature = "tls")]
+pub use rustls;
@@ -0,0 +1,79 @@
+use std::{collections::HashMap, time::Duration};
+
+/// How many times commands ran and how long they took
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CommandMetrics {
+    pub count: u64,
+    pub total_time: Duration,
+}
+
+impl CommandMetrics {
+    fn record(&mut self, duration: Duration) {
+        self.count += 1;
+        self.total_time += duration;
+    }
+}
+
+/// Aggregated timings of the commands a client executed, from sending them
+/// until their replies were parsed
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Metrics {
+    total: CommandMetrics,
+    by_command: HashMap<String, CommandMetrics>,
+}
+
+impl Metrics {
+    pub(crate) fn record(&mut self, command_name: &str, duration: Duration) {
+        self.total.record(duration);
+
+        self.by_command
+            .entry(command_name.to_string())
+            .or_default()
+            .record(duration);
+    }
+
+    /// Returns how many commands were executed
+    pub fn count(&self) -> u64 {
+        self.total.count
+    }
+
+    /// Returns how long all the commands took together
+    pub fn total_time(&self) -> Duration {
+        self.total.total_time
+    }
+
+    /// Returns the metrics of a single command, given its name (e.g. `GET`)
+    pub fn command(&self, name: &str) -> Option<&CommandMetrics> {
+        self.by_command.get(name)
+    }
+
+    /// Returns the metrics of every command that was executed, by name
+    pub fn by_command(&self) -> &HashMap<String, CommandMetrics> {
+        &self.by_command
+    }
+}
+
+#[cfg(test)]
+mod recording {
+    use super::*;
+
+    #[test]
+    fn aggregates_totals_and_commands() {
+        let mut metrics = Metrics::default();
+
+        metrics.record("GET", Duration::from_millis(2));
+        metrics.record("SET", Duration::from_millis(3));
+        metrics.record("GET", Duration::from_millis(4));
+
+        assert_eq!(3, metrics.count());
+        assert_eq!(Duration::from_millis(9), metrics.total_time());
+        assert_eq!(
+            Some(&CommandMetrics {
+                count: 2,
+                total_time: Duration::from_millis(6)
+            }),
+            metrics.command("GET")
+        );
+        assert_eq!(None, metrics.command("DEL"));
+    }
+}
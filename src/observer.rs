@@ -0,0 +1,12 @@
+/// Watches the raw traffic of a [`Client`](crate::client::Client), e.g. to
+/// gather metrics or to assert on it in tests.
+///
+/// Both methods do nothing by default, so implementors only need to
+/// override the ones they care about.
+pub trait Observer: Send {
+    /// Called with the serialized commands right before they're sent
+    fn on_send(&self, _bytes: &[u8]) {}
+
+    /// Called with each chunk of data as it's received
+    fn on_receive(&self, _bytes: &[u8]) {}
+}
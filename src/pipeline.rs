@@ -0,0 +1,118 @@
+use crate::{
+    client::Client,
+    commands::{
+        del::DelArguments,
+        expire::{ExpireArguments, ExpireCondition},
+        get::GetArguments,
+        incr::IncrArguments,
+        set::{SetArguments, SetOptions},
+        Command,
+    },
+    error::CamasError,
+    protocol::ProtocolDataType,
+};
+
+/// A batch of commands that are sent to Redis at once.
+///
+/// Commands are only sent when the pipeline is executed, which saves a round
+/// trip per command. Their replies are returned in the same order the
+/// commands were added.
+///
+/// A pipeline may also be executed as a transaction, in which case its
+/// commands are wrapped in `MULTI`/`EXEC` so they run atomically.
+pub struct Pipeline<'a> {
+    client: &'a mut Client,
+    commands: Vec<Command>,
+    transaction: bool,
+}
+
+impl<'a> Pipeline<'a> {
+    pub(crate) fn new(client: &'a mut Client) -> Self {
+        Self {
+            client,
+            commands: Vec::new(),
+            transaction: false,
+        }
+    }
+
+    pub(crate) fn transaction(client: &'a mut Client) -> Self {
+        Self {
+            transaction: true,
+            ..Self::new(client)
+        }
+    }
+
+    /// Adds a `SET` command to the pipeline.
+    pub fn set<K, V>(&mut self, key: K, value: V, options: SetOptions) -> &mut Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        self.commands
+            .push(Command::Set(SetArguments::new(key, value, options)));
+        self
+    }
+
+    /// Adds a `GET` command to the pipeline.
+    pub fn get<K: ToString>(&mut self, key: K) -> &mut Self {
+        self.commands.push(Command::Get(GetArguments::new(key)));
+        self
+    }
+
+    /// Adds a `DEL` command to the pipeline.
+    pub fn del<K: ToString + Clone>(&mut self, keys: &[K]) -> &mut Self {
+        self.commands
+            .push(Command::Del(DelArguments::new(keys.to_vec())));
+        self
+    }
+
+    /// Adds an `INCR` command to the pipeline.
+    pub fn incr<K: ToString>(&mut self, key: K) -> &mut Self {
+        self.commands.push(Command::Incr(IncrArguments::new(key)));
+        self
+    }
+
+    /// Adds a `DECR` command to the pipeline.
+    pub fn decr<K: ToString>(&mut self, key: K) -> &mut Self {
+        self.commands.push(Command::Decr(IncrArguments::new(key)));
+        self
+    }
+
+    /// Adds an `EXPIRE` command to the pipeline.
+    pub fn expire<K: ToString>(
+        &mut self,
+        key: K,
+        seconds: i64,
+        condition: Option<ExpireCondition>,
+    ) -> &mut Self {
+        self.commands.push(Command::Expire(ExpireArguments::new(
+            key, seconds, condition,
+        )));
+        self
+    }
+
+    /// Returns the number of commands in the pipeline.
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns whether no command was added to the pipeline.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Sends all the commands and returns their replies in order.
+    ///
+    /// Error replies are returned as `ProtocolDataType::SimpleError` (or
+    /// `BulkError`) values instead of failing the whole pipeline.
+    ///
+    /// If this is a transaction and any of the watched keys changed, none of
+    /// the commands run and `CamasError::TransactionAborted` is returned.
+    pub fn execute(self) -> Result<Vec<ProtocolDataType>, CamasError> {
+        if self.transaction {
+            self.client.execute_transaction(self.commands)
+        } else {
+            self.client.execute_pipeline(&self.commands)
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use std::{
+    ops::{Deref, DerefMut},
+    sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+};
+
+use crate::{client::Client, error::CamasError};
+
+/// The connections that aren't checked out, and a way to wait for one to
+/// be returned
+struct Idle {
+    clients: Mutex<Vec<Client>>,
+    returned: Condvar,
+}
+
+impl Idle {
+    fn lock(&self) -> MutexGuard<'_, Vec<Client>> {
+        // A poisoned lock still holds a valid list of connections, since
+        // it's only ever pushed to or popped from
+        self.clients.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A fixed number of connections shared between threads.
+///
+/// Connections are checked out with [`Pool::get`] and go back to the pool
+/// when the returned [`PooledClient`] is dropped. Cloning a pool is cheap,
+/// and the clones share the same connections.
+///
+/// # Example
+///
+/// ```
+/// # use std::{error::Error, thread};
+/// use camas::pool::Pool;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let pool = Pool::builder().url("redis://localhost:6379").size(2).build()?;
+///
+/// let workers = (0..4)
+///     .map(|worker| {
+///         let pool = pool.clone();
+///
+///         thread::spawn(move || {
+///             pool.get()
+///                 .set(format!("pool-key-{}", worker), worker, Default::default())
+///         })
+///     })
+///     .collect::<Vec<_>>();
+///
+/// for worker in workers {
+///     worker.join().unwrap()?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Pool {
+    idle: Arc<Idle>,
+    size: usize,
+}
+
+impl Pool {
+    pub fn builder() -> PoolBuilder {
+        PoolBuilder::default()
+    }
+
+    /// Checks out a connection, waiting for one to be returned if all of
+    /// them are in use
+    pub fn get(&self) -> PooledClient {
+        let mut clients = self.idle.lock();
+
+        loop {
+            if let Some(client) = clients.pop() {
+                return PooledClient {
+                    client: Some(client),
+                    idle: Arc::clone(&self.idle),
+                };
+            }
+
+            clients = self
+                .idle
+                .returned
+                .wait(clients)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// Checks out a connection if one isn't in use
+    pub fn try_get(&self) -> Option<PooledClient> {
+        let client = self.idle.lock().pop()?;
+
+        Some(PooledClient {
+            client: Some(client),
+            idle: Arc::clone(&self.idle),
+        })
+    }
+
+    /// Returns how many connections the pool holds
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns how many connections aren't checked out
+    pub fn available(&self) -> usize {
+        self.idle.lock().len()
+    }
+}
+
+/// Configures and connects a [`Pool`].
+///
+/// By default, the pool holds 4 connections to `redis://localhost:6379`.
+pub struct PoolBuilder {
+    url: String,
+    size: usize,
+}
+
+impl Default for PoolBuilder {
+    fn default() -> Self {
+        Self {
+            url: String::from("redis://localhost:6379"),
+            size: 4,
+        }
+    }
+}
+
+impl PoolBuilder {
+    /// Sets the URL each connection is opened with, in the format accepted
+    /// by [`Client::connect_url`]
+    pub fn url<U: ToString>(mut self, url: U) -> Self {
+        self.url = url.to_string();
+        self
+    }
+
+    /// Sets how many connections the pool holds
+    pub fn size(mut self, size: usize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Opens all the connections, failing if any of them can't be opened
+    pub fn build(self) -> Result<Pool, CamasError> {
+        if self.size == 0 {
+            return Err(CamasError::InvalidArgument(String::from(
+                "A pool needs at least one connection",
+            )));
+        }
+
+        let clients = (0..self.size)
+            .map(|_| Client::connect_url(&self.url))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Pool {
+            idle: Arc::new(Idle {
+                clients: Mutex::new(clients),
+                returned: Condvar::new(),
+            }),
+            size: self.size,
+        })
+    }
+}
+
+/// A connection checked out of a [`Pool`], which is returned to it when
+/// dropped
+pub struct PooledClient {
+    client: Option<Client>,
+    idle: Arc<Idle>,
+}
+
+impl Deref for PooledClient {
+    type Target = Client;
+
+    fn deref(&self) -> &Client {
+        self.client
+            .as_ref()
+            .expect("A pooled client is only taken on drop")
+    }
+}
+
+impl DerefMut for PooledClient {
+    fn deref_mut(&mut self) -> &mut Client {
+        self.client
+            .as_mut()
+            .expect("A pooled client is only taken on drop")
+    }
+}
+
+impl Drop for PooledClient {
+    fn drop(&mut self) {
+        if let Some(client) = self.client.take() {
+            self.idle.lock().push(client);
+
+            self.idle.returned.notify_one();
+        }
+    }
+}
+
+#[cfg(test)]
+mod poisoning {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn recovers_from_a_poisoned_lock() {
+        // Without connections, so no server is needed
+        let pool = Pool {
+            idle: Arc::new(Idle {
+                clients: Mutex::new(Vec::new()),
+                returned: Condvar::new(),
+            }),
+            size: 0,
+        };
+
+        let idle = Arc::clone(&pool.idle);
+        let _ = thread::spawn(move || {
+            let _clients = idle.clients.lock().unwrap();
+
+            panic!("poisoning the pool");
+        })
+        .join();
+
+        assert!(pool.idle.clients.is_poisoned());
+        assert_eq!(0, pool.available());
+        assert!(pool.try_get().is_none());
+    }
+}
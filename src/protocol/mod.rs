@@ -1,9 +1,19 @@
-use std::{cmp::Ordering, error::Error, fmt::Display, str::FromStr};
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
 
 use num_bigint::BigInt;
 
+use crate::{debug::log, error::CamasError};
+
 mod parser;
 
+/// Logs the details of a parsing failure, which are too verbose for the
+/// error itself, and returns the error
+fn parsing_error(err: nom::Err<nom::error::VerboseError<&[u8]>>) -> CamasError {
+    log("PARSING ERROR", err.to_string().as_bytes());
+
+    CamasError::Protocol("Parsing error".into())
+}
+
 /// A Redis data type
 #[derive(Clone, Debug)]
 pub enum ProtocolDataType {
@@ -14,10 +24,29 @@ pub enum ProtocolDataType {
     BigNumber(BigInt),
     BulkError(String),
     BulkString(String),
+    /// A bulk string whose content isn't valid UTF-8
+    BulkBytes(Vec<u8>),
     SimpleError(String),
     SimpleString(String),
     Array(Vec<ProtocolDataType>),
-    // Map(HashMap<ProtocolDataType, ProtocolDataType>),
+    Map(Vec<(ProtocolDataType, ProtocolDataType)>),
+    Set(Vec<ProtocolDataType>),
+    /// Out-of-band data sent by the server (e.g. pub/sub messages and
+    /// client-side caching invalidations), which isn't the reply to any
+    /// command
+    Push(Vec<ProtocolDataType>),
+    /// A string along with a three characters long hint of its format (e.g.
+    /// `txt` or `mkd`)
+    VerbatimString {
+        format: String,
+        content: String,
+    },
+    /// A value preceded by an attribute frame, which carries auxiliary
+    /// metadata about it (e.g. key popularity)
+    Attributed {
+        attributes: Vec<(ProtocolDataType, ProtocolDataType)>,
+        value: Box<ProtocolDataType>,
+    },
 }
 
 impl PartialEq for ProtocolDataType {
@@ -25,8 +54,8 @@ impl PartialEq for ProtocolDataType {
         match (self, other) {
             (ProtocolDataType::Null, ProtocolDataType::Null) => true,
             (ProtocolDataType::Double(lhs), ProtocolDataType::Double(rhs)) => {
-                if lhs.is_nan() == rhs.is_nan() {
-                    return true;
+                if lhs.is_nan() || rhs.is_nan() {
+                    return lhs.is_nan() && rhs.is_nan();
                 }
 
                 lhs.partial_cmp(rhs)
@@ -38,74 +67,210 @@ impl PartialEq for ProtocolDataType {
             (ProtocolDataType::BigNumber(lhs), ProtocolDataType::BigNumber(rhs)) => lhs == rhs,
             (ProtocolDataType::BulkError(lhs), ProtocolDataType::BulkError(rhs)) => lhs == rhs,
             (ProtocolDataType::BulkString(lhs), ProtocolDataType::BulkString(rhs)) => lhs == rhs,
+            (ProtocolDataType::BulkBytes(lhs), ProtocolDataType::BulkBytes(rhs)) => lhs == rhs,
             (ProtocolDataType::SimpleError(lhs), ProtocolDataType::SimpleError(rhs)) => lhs == rhs,
             (ProtocolDataType::SimpleString(lhs), ProtocolDataType::SimpleString(rhs)) => {
                 lhs == rhs
             }
             (ProtocolDataType::Array(lhs), ProtocolDataType::Array(rhs)) => lhs.eq(rhs),
+            (ProtocolDataType::Map(lhs), ProtocolDataType::Map(rhs)) => lhs.eq(rhs),
+            (ProtocolDataType::Set(lhs), ProtocolDataType::Set(rhs)) => lhs.eq(rhs),
+            (ProtocolDataType::Push(lhs), ProtocolDataType::Push(rhs)) => lhs.eq(rhs),
+            (
+                ProtocolDataType::VerbatimString {
+                    format: lhs_format,
+                    content: lhs_content,
+                },
+                ProtocolDataType::VerbatimString {
+                    format: rhs_format,
+                    content: rhs_content,
+                },
+            ) => lhs_format == rhs_format && lhs_content == rhs_content,
+            (
+                ProtocolDataType::Attributed {
+                    attributes: lhs_attributes,
+                    value: lhs_value,
+                },
+                ProtocolDataType::Attributed {
+                    attributes: rhs_attributes,
+                    value: rhs_value,
+                },
+            ) => lhs_attributes == rhs_attributes && lhs_value == rhs_value,
             _ => false,
         }
     }
 }
 
 impl ProtocolDataType {
-    pub(crate) fn serialize(&self) -> String {
+    /// Returns the attributes sent along with the value, if any
+    pub(crate) fn attributes(&self) -> Option<&[(ProtocolDataType, ProtocolDataType)]> {
+        match self {
+            ProtocolDataType::Attributed { attributes, .. } => Some(attributes),
+            _ => None,
+        }
+    }
+
+    /// Returns the value without any attributes sent along with it
+    pub(crate) fn without_attributes(self) -> ProtocolDataType {
+        match self {
+            ProtocolDataType::Attributed { value, .. } => value.without_attributes(),
+            value => value,
+        }
+    }
+
+    /// Returns the text of string-like values without any formatting
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            ProtocolDataType::BulkString(string)
+            | ProtocolDataType::SimpleString(string)
+            | ProtocolDataType::VerbatimString {
+                content: string, ..
+            } => Some(string),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the variant, to point out which one was
+    /// unexpected in error messages
+    pub(crate) fn variant_name(&self) -> &'static str {
+        match self {
+            ProtocolDataType::Null => "Null",
+            ProtocolDataType::Double(_) => "Double",
+            ProtocolDataType::Boolean(_) => "Boolean",
+            ProtocolDataType::Integer(_) => "Integer",
+            ProtocolDataType::BigNumber(_) => "BigNumber",
+            ProtocolDataType::BulkError(_) => "BulkError",
+            ProtocolDataType::BulkString(_) => "BulkString",
+            ProtocolDataType::BulkBytes(_) => "BulkBytes",
+            ProtocolDataType::SimpleError(_) => "SimpleError",
+            ProtocolDataType::SimpleString(_) => "SimpleString",
+            ProtocolDataType::Array(_) => "Array",
+            ProtocolDataType::Map(_) => "Map",
+            ProtocolDataType::Set(_) => "Set",
+            ProtocolDataType::Push(_) => "Push",
+            ProtocolDataType::VerbatimString { .. } => "VerbatimString",
+            ProtocolDataType::Attributed { .. } => "Attributed",
+        }
+    }
+
+    /// Parses a value from the beginning of the input, along with the
+    /// length of the input it took. Returns `None` if the input ends before
+    /// the value is complete.
+    pub(crate) fn parse_partial(input: &[u8]) -> Result<Option<(Self, usize)>, CamasError> {
+        match parser::data_type(input) {
+            Ok((rest, data_type)) => Ok(Some((data_type, input.len() - rest.len()))),
+            Err(nom::Err::Incomplete(_)) => Ok(None),
+            Err(err) => Err(parsing_error(err)),
+        }
+    }
+
+    pub(crate) fn serialize(&self) -> Vec<u8> {
         match self {
             ProtocolDataType::Array(array) => {
                 if array.is_empty() {
-                    return String::from("*0\r\n");
+                    return b"*0\r\n".to_vec();
                 }
 
-                let elements = array
-                    .iter()
-                    .map(|item| item.serialize())
-                    .collect::<String>();
+                let elements = array.iter().flat_map(|item| item.serialize());
 
-                format!("*{}\r\n{}", array.len(), elements)
+                format!("*{}\r\n", array.len())
+                    .into_bytes()
+                    .into_iter()
+                    .chain(elements)
+                    .collect()
             }
             ProtocolDataType::BulkString(string) => {
-                if string.is_empty() {
-                    return String::from("$0\r\n");
-                }
-
-                format!("${}\r\n{}\r\n", string.len(), string)
-            }
-            ProtocolDataType::Integer(integer) => {
-                format!(":{}\r\n", integer)
+                ProtocolDataType::serialize_bulk(string.as_bytes())
             }
-            ProtocolDataType::SimpleString(string) => {
-                format!("+{}\r\n", string)
-            }
-            ProtocolDataType::SimpleError(error) => {
-                format!("-{}\r\n", error)
-            }
-            ProtocolDataType::Null => String::from("_\r\n"),
+            ProtocolDataType::BulkBytes(bytes) => ProtocolDataType::serialize_bulk(bytes),
+            ProtocolDataType::Integer(integer) => format!(":{}\r\n", integer).into_bytes(),
+            ProtocolDataType::SimpleString(string) => format!("+{}\r\n", string).into_bytes(),
+            ProtocolDataType::SimpleError(error) => format!("-{}\r\n", error).into_bytes(),
+            ProtocolDataType::Null => b"_\r\n".to_vec(),
             ProtocolDataType::Boolean(boolean) => {
-                format!("#{}\r\n", if *boolean { 't' } else { 'f' })
+                format!("#{}\r\n", if *boolean { 't' } else { 'f' }).into_bytes()
             }
             ProtocolDataType::Double(double) => {
                 if double.is_nan() {
-                    return String::from(",nan\r\n");
+                    return b",nan\r\n".to_vec();
                 }
 
-                format!(",{}\r\n", double)
+                // Plain notation would spell out every digit of very large or
+                // very small doubles, so those use scientific notation instead
+                let magnitude = double.abs();
+
+                if magnitude.is_finite() && magnitude != 0.0 && !(1e-4..1e16).contains(&magnitude) {
+                    format!(",{:e}\r\n", double).into_bytes()
+                } else {
+                    format!(",{}\r\n", double).into_bytes()
+                }
             }
-            ProtocolDataType::BigNumber(number) => {
-                format!("({}\r\n", number)
+            ProtocolDataType::BigNumber(number) => format!("({}\r\n", number).into_bytes(),
+            ProtocolDataType::Map(map) => {
+                if map.is_empty() {
+                    return b"%0\r\n".to_vec();
+                }
+
+                let elements = map
+                    .iter()
+                    .flat_map(|(key, value)| [key.serialize(), value.serialize()].concat());
+
+                format!("%{}\r\n", map.len())
+                    .into_bytes()
+                    .into_iter()
+                    .chain(elements)
+                    .collect()
             }
-            // ProtocolDataType::Map(map) => {
-            //     let elements = map
-            //         .iter()
-            //         .map(|(key, value)| format!("{}{}", key.serialize(), value.serialize()))
-            //         .collect::<String>();
-
-            //     format!("%{}\r\n{}\r\n", map.len(), elements)
-            // }
             ProtocolDataType::BulkError(error) => {
-                format!("!{}\r\n{}\r\n", error.len(), error)
+                format!("!{}\r\n{}\r\n", error.len(), error).into_bytes()
+            }
+            ProtocolDataType::Set(set) => {
+                if set.is_empty() {
+                    return b"~0\r\n".to_vec();
+                }
+
+                let elements = set.iter().flat_map(|item| item.serialize());
+
+                format!("~{}\r\n", set.len())
+                    .into_bytes()
+                    .into_iter()
+                    .chain(elements)
+                    .collect()
+            }
+            ProtocolDataType::Push(push) => {
+                if push.is_empty() {
+                    return b">0\r\n".to_vec();
+                }
+
+                let elements = push.iter().flat_map(|item| item.serialize());
+
+                format!(">{}\r\n", push.len())
+                    .into_bytes()
+                    .into_iter()
+                    .chain(elements)
+                    .collect()
+            }
+            ProtocolDataType::VerbatimString { format, content } => {
+                format!("={}\r\n{}:{}\r\n", content.len() + 4, format, content).into_bytes()
+            }
+            ProtocolDataType::Attributed { attributes, value } => {
+                let elements = attributes
+                    .iter()
+                    .flat_map(|(key, value)| [key.serialize(), value.serialize()].concat());
+
+                format!("|{}\r\n", attributes.len())
+                    .into_bytes()
+                    .into_iter()
+                    .chain(elements)
+                    .chain(value.serialize())
+                    .collect()
             }
         }
     }
+
+    fn serialize_bulk(bytes: &[u8]) -> Vec<u8> {
+        [format!("${}\r\n", bytes.len()).as_bytes(), bytes, b"\r\n"].concat()
+    }
 }
 
 impl Display for ProtocolDataType {
@@ -113,6 +278,9 @@ impl Display for ProtocolDataType {
         match self {
             ProtocolDataType::Null => f.write_str("null"),
             ProtocolDataType::BulkString(string) => f.write_fmt(format_args!("\"{}\"", string)),
+            ProtocolDataType::BulkBytes(bytes) => {
+                f.write_fmt(format_args!("\"{}\"", bytes.escape_ascii()))
+            }
             ProtocolDataType::Integer(integer) => f.write_str(integer.to_string().as_str()),
             ProtocolDataType::SimpleString(string) => f.write_str(string.to_string().as_str()),
             ProtocolDataType::SimpleError(error) => f.write_str(error.to_string().as_str()),
@@ -128,29 +296,49 @@ impl Display for ProtocolDataType {
                     .join(",");
 
                 f.write_fmt(format_args!("[{}]", items))
-            } // ProtocolDataType::Map(map) => {
-              //     let elements = map
-              //         .iter()
-              //         .map(|(key, value)| format!("\t{}: {}", key.to_string(), value.to_string()))
-              //         .collect::<Vec<String>>()
-              //         .join("\n");
-
-              //     f.write_fmt(format_args!("{{\n{}\n}}", elements))
-              // }
+            }
+            ProtocolDataType::Map(map) => {
+                let elements = map
+                    .iter()
+                    .map(|(key, value)| format!("{}: {}", key, value))
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                f.write_fmt(format_args!("{{{}}}", elements))
+            }
+            ProtocolDataType::Set(set) => {
+                let items = set
+                    .iter()
+                    .map(|item| item.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                f.write_fmt(format_args!("~[{}]", items))
+            }
+            ProtocolDataType::Push(push) => {
+                let items = push
+                    .iter()
+                    .map(|item| item.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                f.write_fmt(format_args!(">[{}]", items))
+            }
+            ProtocolDataType::VerbatimString { content, .. } => {
+                f.write_fmt(format_args!("\"{}\"", content))
+            }
+            ProtocolDataType::Attributed { value, .. } => value.fmt(f),
         }
     }
 }
 
 impl FromStr for ProtocolDataType {
-    type Err = Box<dyn Error>;
+    type Err = CamasError;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        match parser::data_type(value) {
+        match parser::data_type(value.as_bytes()) {
             Ok((_, data_type)) => Ok(data_type),
-            Err(err) => {
-                eprintln!("{err}");
-                Err("Parsing error".into())
-            }
+            Err(err) => Err(parsing_error(err)),
         }
     }
 }
@@ -168,6 +356,7 @@ impl From<i64> for ProtocolDataType {
 }
 
 #[cfg(test)]
+#[allow(clippy::approx_constant)]
 mod serialization {
     use super::*;
 
@@ -175,70 +364,84 @@ mod serialization {
     fn serializes_null() {
         let result = ProtocolDataType::Null.serialize();
 
-        assert_eq!(result, "_\r\n");
+        assert_eq!(result, b"_\r\n");
     }
 
     #[test]
     fn serializes_double_with_no_fractional_part() {
         let result = ProtocolDataType::Double(3_f64).serialize();
 
-        assert_eq!(result, ",3\r\n");
+        assert_eq!(result, b",3\r\n");
     }
 
     #[test]
     fn serializes_double_with_fractional_part() {
         let result = ProtocolDataType::Double(3.141592).serialize();
 
-        assert_eq!(result, ",3.141592\r\n");
+        assert_eq!(result, b",3.141592\r\n");
     }
 
     #[test]
     fn serializes_double_with_infinity() {
         let result = ProtocolDataType::Double(f64::INFINITY).serialize();
 
-        assert_eq!(result, ",inf\r\n");
+        assert_eq!(result, b",inf\r\n");
     }
 
     #[test]
     fn serializes_double_with_negative_infinity() {
         let result = ProtocolDataType::Double(f64::NEG_INFINITY).serialize();
 
-        assert_eq!(result, ",-inf\r\n");
+        assert_eq!(result, b",-inf\r\n");
     }
 
     #[test]
     fn serializes_double_with_not_a_number() {
         let result = ProtocolDataType::Double(f64::NAN).serialize();
 
-        assert_eq!(result, ",nan\r\n");
+        assert_eq!(result, b",nan\r\n");
+    }
+
+    #[test]
+    fn serializes_large_double_in_scientific_notation() {
+        let result = ProtocolDataType::Double(1e300).serialize();
+
+        assert_eq!(result, b",1e300\r\n");
+    }
+
+    #[test]
+    fn serializes_small_double_in_scientific_notation() {
+        let result = ProtocolDataType::Double(-1.5e-7).serialize();
+
+        assert_eq!(result, b",-1.5e-7\r\n");
     }
 
     #[test]
     fn serializes_boolean_true() {
         let result = ProtocolDataType::Boolean(true).serialize();
 
-        assert_eq!(result, "#t\r\n");
+        assert_eq!(result, b"#t\r\n");
     }
 
     #[test]
     fn serializes_boolean_false() {
         let result = ProtocolDataType::Boolean(false).serialize();
 
-        assert_eq!(result, "#f\r\n");
+        assert_eq!(result, b"#f\r\n");
     }
 
     #[test]
     fn serializes_positive_integer() {
         let result = ProtocolDataType::Integer(42).serialize();
 
-        assert_eq!(result, ":42\r\n");
+        assert_eq!(result, b":42\r\n");
     }
 
     #[test]
     fn serializes_negative_integer() {
         let result = ProtocolDataType::Integer(-42).serialize();
 
-        assert_eq!(result, ":-42\r\n");
+        assert_eq!(result, b":-42\r\n");
     }
 
     #[test]
@@ -247,7 +450,7 @@ mod serialization {
 
         let result = ProtocolDataType::BigNumber(BigInt::from_str(value).unwrap()).serialize();
 
-        let expected = format!("({}\r\n", value);
+        let expected = format!("({}\r\n", value).into_bytes();
 
         assert_eq!(result, expected);
     }
@@ -258,7 +461,7 @@ mod serialization {
 
         let result = ProtocolDataType::BigNumber(BigInt::from_str(value).unwrap()).serialize();
 
-        let expected = format!("({}\r\n", value);
+        let expected = format!("({}\r\n", value).into_bytes();
 
         assert_eq!(result, expected);
     }
@@ -267,35 +470,42 @@ mod serialization {
     fn serializes_bulk_error() {
         let result = ProtocolDataType::BulkError("Some error".into()).serialize();
 
-        assert_eq!(result, "!10\r\nSome error\r\n");
+        assert_eq!(result, b"!10\r\nSome error\r\n");
     }
 
     #[test]
     fn serializes_bulk_string() {
         let result = ProtocolDataType::BulkString("Some string".into()).serialize();
 
-        assert_eq!(result, "$11\r\nSome string\r\n");
+        assert_eq!(result, b"$11\r\nSome string\r\n");
     }
 
     #[test]
     fn serializes_bulk_string_with_zero_length() {
         let result = ProtocolDataType::BulkString("".into()).serialize();
 
-        assert_eq!(result, "$0\r\n");
+        assert_eq!(result, b"$0\r\n\r\n");
+    }
+
+    #[test]
+    fn serializes_bulk_bytes() {
+        let result = ProtocolDataType::BulkBytes(vec![0xFF, 0x00]).serialize();
+
+        assert_eq!(result, b"$2\r\n\xFF\x00\r\n");
     }
 
     #[test]
     fn serializes_simple_error() {
         let result = ProtocolDataType::SimpleError("ERR Some error".into()).serialize();
 
-        assert_eq!(result, "-ERR Some error\r\n");
+        assert_eq!(result, b"-ERR Some error\r\n");
     }
 
     #[test]
     fn serializes_simple_string() {
         let result = ProtocolDataType::SimpleString("OK".into()).serialize();
 
-        assert_eq!(result, "+OK\r\n");
+        assert_eq!(result, b"+OK\r\n");
     }
 
     #[test]
@@ -307,7 +517,7 @@ mod serialization {
         ])
         .serialize();
 
-        assert_eq!(result, "*3\r\n$3\r\nFoo\r\n:42\r\n#t\r\n");
+        assert_eq!(result, b"*3\r\n$3\r\nFoo\r\n:42\r\n#t\r\n");
     }
 
     #[test]
@@ -321,19 +531,133 @@ mod serialization {
         ])
         .serialize();
 
-        assert_eq!(result, "*2\r\n$3\r\nFoo\r\n*2\r\n#t\r\n:42\r\n");
+        assert_eq!(result, b"*2\r\n$3\r\nFoo\r\n*2\r\n#t\r\n:42\r\n");
     }
 
     #[test]
     fn serializes_array_with_no_items() {
         let result = ProtocolDataType::Array(vec![]).serialize();
 
-        assert_eq!(result, "*0\r\n");
+        assert_eq!(result, b"*0\r\n");
+    }
+
+    #[test]
+    fn serializes_set() {
+        let result = ProtocolDataType::Set(vec![
+            ProtocolDataType::BulkString("Foo".into()),
+            ProtocolDataType::Integer(42),
+            ProtocolDataType::Boolean(true),
+        ])
+        .serialize();
+
+        assert_eq!(result, b"~3\r\n$3\r\nFoo\r\n:42\r\n#t\r\n");
+    }
+
+    #[test]
+    fn serializes_nested_set() {
+        let result = ProtocolDataType::Set(vec![
+            ProtocolDataType::BulkString("Foo".into()),
+            ProtocolDataType::Set(vec![
+                ProtocolDataType::Boolean(true),
+                ProtocolDataType::Integer(42),
+            ]),
+        ])
+        .serialize();
+
+        assert_eq!(result, b"~2\r\n$3\r\nFoo\r\n~2\r\n#t\r\n:42\r\n");
+    }
+
+    #[test]
+    fn serializes_set_with_no_items() {
+        let result = ProtocolDataType::Set(vec![]).serialize();
+
+        assert_eq!(result, b"~0\r\n");
+    }
+
+    #[test]
+    fn serializes_push() {
+        let result = ProtocolDataType::Push(vec![
+            ProtocolDataType::BulkString("message".into()),
+            ProtocolDataType::BulkString("news".into()),
+            ProtocolDataType::BulkString("Hello".into()),
+        ])
+        .serialize();
+
+        assert_eq!(
+            result,
+            b">3\r\n$7\r\nmessage\r\n$4\r\nnews\r\n$5\r\nHello\r\n"
+        );
+    }
+
+    #[test]
+    fn serializes_verbatim_string() {
+        let result = ProtocolDataType::VerbatimString {
+            format: "txt".into(),
+            content: "Some string".into(),
+        }
+        .serialize();
+
+        assert_eq!(result, b"=15\r\ntxt:Some string\r\n");
+    }
+
+    #[test]
+    fn serializes_attributed_value() {
+        let result = ProtocolDataType::Attributed {
+            attributes: vec![(
+                ProtocolDataType::SimpleString("ttl".into()),
+                ProtocolDataType::Integer(3600),
+            )],
+            value: Box::new(ProtocolDataType::BulkString("Foo".into())),
+        }
+        .serialize();
+
+        assert_eq!(result, b"|1\r\n+ttl\r\n:3600\r\n$3\r\nFoo\r\n");
+    }
+
+    #[test]
+    fn serializes_map() {
+        let result = ProtocolDataType::Map(vec![
+            (
+                ProtocolDataType::SimpleString("first".into()),
+                ProtocolDataType::Integer(1),
+            ),
+            (
+                ProtocolDataType::SimpleString("second".into()),
+                ProtocolDataType::BulkString("Foo".into()),
+            ),
+        ])
+        .serialize();
+
+        assert_eq!(result, b"%2\r\n+first\r\n:1\r\n+second\r\n$3\r\nFoo\r\n");
+    }
+
+    #[test]
+    fn serializes_nested_map() {
+        let result = ProtocolDataType::Map(vec![(
+            ProtocolDataType::BulkString("Foo".into()),
+            ProtocolDataType::Map(vec![(
+                ProtocolDataType::Integer(42),
+                ProtocolDataType::Boolean(true),
+            )]),
+        )])
+        .serialize();
+
+        assert_eq!(result, b"%1\r\n$3\r\nFoo\r\n%1\r\n:42\r\n#t\r\n");
+    }
+
+    #[test]
+    fn serializes_map_with_no_items() {
+        let result = ProtocolDataType::Map(vec![]).serialize();
+
+        assert_eq!(result, b"%0\r\n");
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::approx_constant)]
 mod parsing {
+    use std::error::Error;
+
     use super::*;
 
     #[test]
@@ -371,7 +695,7 @@ mod parsing {
 
     #[test]
     fn parses_negative_double_with_no_fractional_part() -> Result<(), Box<dyn Error>> {
-        let expected = ProtocolDataType::Double(3.0);
+        let expected = ProtocolDataType::Double(-3.0);
 
         let result: ProtocolDataType = ",-3\r\n".parse()?;
 
@@ -404,7 +728,7 @@ mod parsing {
 
     #[test]
     fn parses_negative_double_with_fractional_part() -> Result<(), Box<dyn Error>> {
-        let expected = ProtocolDataType::Double(3.141592);
+        let expected = ProtocolDataType::Double(-3.141592);
 
         let result: ProtocolDataType = ",-3.141592\r\n".parse()?;
 
@@ -413,6 +737,62 @@ mod parsing {
         Ok(())
     }
 
+    #[test]
+    fn parses_double_with_exponent() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Double(1e10);
+
+        let result: ProtocolDataType = ",1e10\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_double_with_fractional_part_and_uppercase_exponent() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Double(3000.0);
+
+        let result: ProtocolDataType = ",3.0E3\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_negative_double_with_negative_exponent() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Double(-0.0015);
+
+        let result: ProtocolDataType = ",-1.5e-3\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trips_double_in_scientific_notation() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Double(-2.5e-300);
+
+        let result: ProtocolDataType = String::from_utf8(expected.serialize())?.parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn does_not_consider_different_doubles_equal() {
+        assert_ne!(
+            ProtocolDataType::Double(3.0),
+            ProtocolDataType::Double(-3.0)
+        );
+        assert_ne!(
+            ProtocolDataType::Double(3.0),
+            ProtocolDataType::Double(f64::NAN)
+        );
+    }
+
     #[test]
     fn parses_double_with_infinity() -> Result<(), Box<dyn Error>> {
         let expected = ProtocolDataType::Double(f64::INFINITY);
@@ -446,6 +826,20 @@ mod parsing {
         Ok(())
     }
 
+    #[test]
+    fn parses_double_with_not_a_number_without_sign() -> Result<(), Box<dyn Error>> {
+        let result: ProtocolDataType = ",nan\r\n".parse()?;
+
+        let ProtocolDataType::Double(double) = result else {
+            panic!("expected a double, got {result:?}");
+        };
+
+        assert!(double.is_nan());
+        assert!(double.is_sign_positive());
+
+        Ok(())
+    }
+
     #[test]
     fn parses_boolean_true() -> Result<(), Box<dyn Error>> {
         let expected = ProtocolDataType::Boolean(true);
@@ -549,11 +943,33 @@ mod parsing {
         Ok(())
     }
 
+    #[test]
+    fn parses_bulk_string_with_multibyte_characters() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::BulkString(String::from("camará"));
+
+        let result: ProtocolDataType = "$7\r\ncamará\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_bulk_string_with_invalid_utf8_as_bytes() -> Result<(), Box<dyn Error>> {
+        let expected = Some((ProtocolDataType::BulkBytes(vec![0xFF, 0x00]), 8));
+
+        let result = ProtocolDataType::parse_partial(b"$2\r\n\xFF\x00\r\n")?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
     #[test]
     fn parses_bulk_string_with_zero_length() -> Result<(), Box<dyn Error>> {
         let expected = ProtocolDataType::BulkString(String::new());
 
-        let result: ProtocolDataType = "$0\r\n".parse()?;
+        let result: ProtocolDataType = "$0\r\n\r\n".parse()?;
 
         assert_eq!(expected, result);
 
@@ -608,6 +1024,17 @@ mod parsing {
         Ok(())
     }
 
+    #[test]
+    fn parses_nil_array() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Null;
+
+        let result: ProtocolDataType = "*-1\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
     #[test]
     fn parses_nested_array() -> Result<(), Box<dyn Error>> {
         let expected = ProtocolDataType::Array(vec![
@@ -629,4 +1056,363 @@ mod parsing {
 
         Ok(())
     }
+
+    #[test]
+    fn parses_nested_arrays_with_single_items() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Array(vec![
+            ProtocolDataType::Array(vec![ProtocolDataType::Integer(1)]),
+            ProtocolDataType::Array(vec![ProtocolDataType::Integer(2)]),
+        ]);
+
+        let result: ProtocolDataType = "*2\r\n*1\r\n:1\r\n*1\r\n:2\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_integer_that_is_not_a_number() {
+        let result = ":notanumber\r\n".parse::<ProtocolDataType>();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn fails_to_parse_bulk_string_without_length() {
+        let result = "$xx\r\n".parse::<ProtocolDataType>();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn fails_to_parse_array_without_length() {
+        let result = "*xx\r\n".parse::<ProtocolDataType>();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn fails_to_parse_double_that_is_not_a_number() {
+        let result = ",notanumber\r\n".parse::<ProtocolDataType>();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn partially_parses_complete_value() -> Result<(), Box<dyn Error>> {
+        let expected = Some((ProtocolDataType::BulkString("hello".into()), 11));
+
+        let result = ProtocolDataType::parse_partial(b"$5\r\nhello\r\n")?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partially_parses_only_first_of_many_values() -> Result<(), Box<dyn Error>> {
+        let expected = Some((ProtocolDataType::Integer(1), 4));
+
+        let result = ProtocolDataType::parse_partial(b":1\r\n:2\r\n")?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partially_parses_empty_bulk_string_with_its_terminator() -> Result<(), Box<dyn Error>> {
+        let expected = Some((ProtocolDataType::BulkString(String::new()), 6));
+
+        let result = ProtocolDataType::parse_partial(b"$0\r\n\r\n:1\r\n")?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partially_parses_to_none_when_empty_bulk_string_is_incomplete() -> Result<(), Box<dyn Error>>
+    {
+        let result = ProtocolDataType::parse_partial(b"$0\r\n")?;
+
+        assert_eq!(None, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partially_parses_to_none_when_bulk_string_is_incomplete() -> Result<(), Box<dyn Error>> {
+        let result = ProtocolDataType::parse_partial(b"$5\r\nhel")?;
+
+        assert_eq!(None, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partially_parses_to_none_when_array_is_missing_elements() -> Result<(), Box<dyn Error>> {
+        let result = ProtocolDataType::parse_partial(b"*2\r\n:1\r\n")?;
+
+        assert_eq!(None, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_set() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Set(vec![
+            ProtocolDataType::BulkString(String::from("Foo")),
+            ProtocolDataType::Integer(42),
+            ProtocolDataType::Boolean(true),
+        ]);
+
+        let result: ProtocolDataType = "~3\r\n$3\r\nFoo\r\n:42\r\n#t\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_set_with_no_items() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Set(Vec::new());
+
+        let result: ProtocolDataType = "~0\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_nested_set() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Set(vec![
+            ProtocolDataType::Set(vec![
+                ProtocolDataType::Integer(1),
+                ProtocolDataType::Integer(2),
+            ]),
+            ProtocolDataType::Array(vec![ProtocolDataType::SimpleString(String::from("Hello"))]),
+        ]);
+
+        let result: ProtocolDataType = "~2\r\n~2\r\n:1\r\n:2\r\n*1\r\n+Hello\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_push() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Push(vec![
+            ProtocolDataType::BulkString(String::from("message")),
+            ProtocolDataType::BulkString(String::from("news")),
+            ProtocolDataType::BulkString(String::from("Hello")),
+        ]);
+
+        let result: ProtocolDataType =
+            ">3\r\n$7\r\nmessage\r\n$4\r\nnews\r\n$5\r\nHello\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_push_with_no_items() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Push(Vec::new());
+
+        let result: ProtocolDataType = ">0\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_verbatim_string() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::VerbatimString {
+            format: String::from("txt"),
+            content: String::from("Some string"),
+        };
+
+        let result: ProtocolDataType = "=15\r\ntxt:Some string\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_verbatim_string_with_no_content() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::VerbatimString {
+            format: String::from("txt"),
+            content: String::new(),
+        };
+
+        let result: ProtocolDataType = "=4\r\ntxt:\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_serialized_text_verbatim_string() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::VerbatimString {
+            format: String::from("txt"),
+            content: String::from("# Server\r\nredis_version:7.2.4\r\n"),
+        };
+
+        let result: ProtocolDataType = String::from_utf8(expected.serialize())?.parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_serialized_markdown_verbatim_string() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::VerbatimString {
+            format: String::from("mkd"),
+            content: String::from("# Title\n\nSome *emphasized* text"),
+        };
+
+        let result: ProtocolDataType = String::from_utf8(expected.serialize())?.parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_map() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Map(vec![
+            (
+                ProtocolDataType::SimpleString(String::from("first")),
+                ProtocolDataType::Integer(1),
+            ),
+            (
+                ProtocolDataType::SimpleString(String::from("second")),
+                ProtocolDataType::BulkString(String::from("Foo")),
+            ),
+        ]);
+
+        let result: ProtocolDataType = "%2\r\n+first\r\n:1\r\n+second\r\n$3\r\nFoo\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_map_with_no_items() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Map(Vec::new());
+
+        let result: ProtocolDataType = "%0\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_nested_map_with_mixed_types() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Map(vec![
+            (
+                ProtocolDataType::BulkString(String::from("server")),
+                ProtocolDataType::BulkString(String::from("redis")),
+            ),
+            (
+                ProtocolDataType::BulkString(String::from("modules")),
+                ProtocolDataType::Array(vec![ProtocolDataType::Map(vec![(
+                    ProtocolDataType::BulkString(String::from("ver")),
+                    ProtocolDataType::Integer(20809),
+                )])]),
+            ),
+            (
+                ProtocolDataType::Integer(42),
+                ProtocolDataType::Map(vec![(
+                    ProtocolDataType::Boolean(true),
+                    ProtocolDataType::Null,
+                )]),
+            ),
+        ]);
+
+        let result: ProtocolDataType = "%3\r\n$6\r\nserver\r\n$5\r\nredis\r\n$7\r\nmodules\r\n*1\r\n%1\r\n$3\r\nver\r\n:20809\r\n:42\r\n%1\r\n#t\r\n_\r\n"
+            .parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_serialized_map() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Map(vec![
+            (
+                ProtocolDataType::BulkString(String::from("Foo")),
+                ProtocolDataType::Map(vec![(
+                    ProtocolDataType::Integer(42),
+                    ProtocolDataType::Array(vec![
+                        ProtocolDataType::SimpleString(String::from("Hello")),
+                        ProtocolDataType::Boolean(false),
+                    ]),
+                )]),
+            ),
+            (
+                ProtocolDataType::SimpleString(String::from("Bar")),
+                ProtocolDataType::Double(1.5),
+            ),
+        ]);
+
+        let result: ProtocolDataType = String::from_utf8(expected.serialize())?.parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_attributed_bulk_string() -> Result<(), Box<dyn Error>> {
+        let attributes = vec![(
+            ProtocolDataType::SimpleString(String::from("key-popularity")),
+            ProtocolDataType::Map(vec![(
+                ProtocolDataType::BulkString(String::from("foo")),
+                ProtocolDataType::Double(0.1923),
+            )]),
+        )];
+
+        let result: ProtocolDataType =
+            "|1\r\n+key-popularity\r\n%1\r\n$3\r\nfoo\r\n,0.1923\r\n$5\r\nHello\r\n".parse()?;
+
+        assert_eq!(result.attributes(), Some(attributes.as_slice()));
+        assert_eq!(
+            result.without_attributes(),
+            ProtocolDataType::BulkString(String::from("Hello"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_array_with_attributed_item() -> Result<(), Box<dyn Error>> {
+        let expected = ProtocolDataType::Array(vec![
+            ProtocolDataType::Integer(1),
+            ProtocolDataType::Attributed {
+                attributes: vec![(
+                    ProtocolDataType::SimpleString(String::from("ttl")),
+                    ProtocolDataType::Integer(3600),
+                )],
+                value: Box::new(ProtocolDataType::Integer(2)),
+            },
+        ]);
+
+        let result: ProtocolDataType = "*2\r\n:1\r\n|1\r\n+ttl\r\n:3600\r\n:2\r\n".parse()?;
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
 }
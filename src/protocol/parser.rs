@@ -1,13 +1,13 @@
-use std::str::FromStr;
+use std::{error::Error, str::FromStr, str::Utf8Error};
 
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take, take_until, take_while},
+    bytes::streaming::{tag, take, take_until, take_while},
     character::{
-        complete::{char, crlf},
         is_digit,
+        streaming::{char, crlf},
     },
-    combinator::map,
+    combinator::{map, map_res},
     error::VerboseError,
     multi::many_m_n,
     sequence::{delimited, preceded, tuple},
@@ -16,62 +16,96 @@ use nom::{
 
 use super::ProtocolDataType;
 
-fn bulk_string_with_content(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
-    let (rest, count) = map(
-        preceded(char('$'), take_while(|a: char| is_digit(a as u8))),
-        |value| u32::from_str(value).unwrap(),
-    )(input)?;
+/// Reads a frame's content as text, which the protocol guarantees for
+/// everything but bulk strings
+fn text(input: &[u8]) -> Result<String, Utf8Error> {
+    std::str::from_utf8(input).map(str::to_string)
+}
 
-    map(delimited(crlf, take(count), crlf), |value: &str| {
-        ProtocolDataType::BulkString(value.to_string())
-    })(rest)
+/// Reads a frame's content as a number
+fn number<T: FromStr>(input: &[u8]) -> Result<T, Box<dyn Error>> {
+    Ok(std::str::from_utf8(input)?
+        .parse()
+        .map_err(|_| "Invalid number")?)
+}
+
+fn bulk_string_with_content(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    let (rest, count) = map_res(preceded(char('$'), take_while(is_digit)), number::<u32>)(input)?;
+
+    map(
+        delimited(crlf, take(count), crlf),
+        |value: &[u8]| match std::str::from_utf8(value) {
+            Ok(string) => ProtocolDataType::BulkString(string.to_string()),
+            Err(_) => ProtocolDataType::BulkBytes(value.to_vec()),
+        },
+    )(rest)
 }
 
-fn bulk_string_nil(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn bulk_string_nil(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     map(tuple((tag("$-1"), crlf)), |_| ProtocolDataType::Null)(input)
 }
 
-fn bulk_string_empty(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
-    map(tuple((tag("$0"), crlf)), |_| {
+fn bulk_string_empty(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    map(tuple((tag("$0"), crlf, crlf)), |_| {
         ProtocolDataType::BulkString(String::new())
     })(input)
 }
 
-fn bulk_string(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn bulk_string(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     alt((bulk_string_nil, bulk_string_empty, bulk_string_with_content))(input)
 }
 
-fn simple_string(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn verbatim_string(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    let (rest, count) = map_res(preceded(char('='), take_while(is_digit)), number::<u32>)(input)?;
+
+    map(
+        delimited(
+            crlf,
+            tuple((
+                map_res(take(3usize), text),
+                preceded(char(':'), map_res(take(count.saturating_sub(4)), text)),
+            )),
+            crlf,
+        ),
+        |(format, content)| ProtocolDataType::VerbatimString { format, content },
+    )(rest)
+}
+
+fn simple_string(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     map(
-        delimited(char('+'), take_until("\r\n"), crlf),
-        |text: &str| ProtocolDataType::SimpleString(text.to_string()),
+        map_res(delimited(char('+'), take_until("\r\n"), crlf), text),
+        ProtocolDataType::SimpleString,
     )(input)
 }
 
-fn simple_error(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn simple_error(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     map(
-        delimited(char('-'), take_until("\r\n"), crlf),
-        |text: &str| ProtocolDataType::SimpleError(text.to_string()),
+        map_res(delimited(char('-'), take_until("\r\n"), crlf), text),
+        ProtocolDataType::SimpleError,
     )(input)
 }
 
-fn integer(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn integer(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     map(
-        delimited(char(':'), take_until("\r\n"), crlf),
-        |integer_str: &str| ProtocolDataType::Integer(integer_str.parse().unwrap()),
+        map_res(delimited(char(':'), take_until("\r\n"), crlf), number),
+        ProtocolDataType::Integer,
     )(input)
 }
 
-fn array_empty(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn array_nil(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    map(tuple((tag("*-1"), crlf)), |_| ProtocolDataType::Null)(input)
+}
+
+fn array_empty(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     map(tuple((tag("*0"), crlf)), |_| {
         ProtocolDataType::Array(Vec::new())
     })(input)
 }
 
-fn array_with_elements(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
-    let (rest, count) = map(
-        delimited(char('*'), take_while(|a: char| is_digit(a as u8)), crlf),
-        |value| usize::from_str(value).unwrap(),
+fn array_with_elements(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    let (rest, count) = map_res(
+        delimited(char('*'), take_while(is_digit), crlf),
+        number::<usize>,
     )(input)?;
 
     map(many_m_n(count, count, data_type), |elements| {
@@ -79,51 +113,129 @@ fn array_with_elements(input: &str) -> IResult<&str, ProtocolDataType, VerboseEr
     })(rest)
 }
 
-fn array(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
-    alt((array_empty, array_with_elements))(input)
+fn array(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    alt((array_nil, array_empty, array_with_elements))(input)
 }
 
-fn boolean_true(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn map_empty(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    map(tuple((tag("%0"), crlf)), |_| {
+        ProtocolDataType::Map(Vec::new())
+    })(input)
+}
+
+fn map_with_elements(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    let (rest, count) = map_res(
+        delimited(char('%'), take_while(is_digit), crlf),
+        number::<usize>,
+    )(input)?;
+
+    map(
+        many_m_n(count, count, tuple((data_type, data_type))),
+        ProtocolDataType::Map,
+    )(rest)
+}
+
+fn map_type(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    alt((map_empty, map_with_elements))(input)
+}
+
+fn set_empty(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    map(tuple((tag("~0"), crlf)), |_| {
+        ProtocolDataType::Set(Vec::new())
+    })(input)
+}
+
+fn set_with_elements(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    let (rest, count) = map_res(
+        delimited(char('~'), take_while(is_digit), crlf),
+        number::<usize>,
+    )(input)?;
+
+    map(many_m_n(count, count, data_type), ProtocolDataType::Set)(rest)
+}
+
+fn set(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    alt((set_empty, set_with_elements))(input)
+}
+
+fn push_empty(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    map(tuple((tag(">0"), crlf)), |_| {
+        ProtocolDataType::Push(Vec::new())
+    })(input)
+}
+
+fn push_with_elements(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    let (rest, count) = map_res(
+        delimited(char('>'), take_while(is_digit), crlf),
+        number::<usize>,
+    )(input)?;
+
+    map(many_m_n(count, count, data_type), ProtocolDataType::Push)(rest)
+}
+
+fn push(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    alt((push_empty, push_with_elements))(input)
+}
+
+fn attributed(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    let (rest, count) = map_res(
+        delimited(char('|'), take_while(is_digit), crlf),
+        number::<usize>,
+    )(input)?;
+
+    map(
+        tuple((
+            many_m_n(count, count, tuple((data_type, data_type))),
+            data_type,
+        )),
+        |(attributes, value)| ProtocolDataType::Attributed {
+            attributes,
+            value: Box::new(value),
+        },
+    )(rest)
+}
+
+fn boolean_true(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     map(tuple((tag("#t"), crlf)), |_| {
         ProtocolDataType::Boolean(true)
     })(input)
 }
 
-fn boolean_false(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn boolean_false(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     map(tuple((tag("#f"), crlf)), |_| {
         ProtocolDataType::Boolean(false)
     })(input)
 }
 
-fn boolean(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn boolean(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     alt((boolean_true, boolean_false))(input)
 }
 
-fn double_infinity(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn double_infinity(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     map(tuple((tag(",inf"), crlf)), |_| {
         ProtocolDataType::Double(f64::INFINITY)
     })(input)
 }
 
-fn double_negative_infinity(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn double_negative_infinity(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     map(tuple((tag(",-inf"), crlf)), |_| {
         ProtocolDataType::Double(f64::NEG_INFINITY)
     })(input)
 }
-fn double_not_a_number(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn double_not_a_number(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     map(tuple((tag(",nan"), crlf)), |_| {
         ProtocolDataType::Double(f64::NAN)
     })(input)
 }
 
-fn double_number(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn double_number(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     map(
-        delimited(char(','), take_until("\r\n"), crlf),
-        |double_str: &str| ProtocolDataType::Double(double_str.parse().unwrap()),
+        map_res(delimited(char(','), take_until("\r\n"), crlf), number),
+        ProtocolDataType::Double,
     )(input)
 }
 
-fn double(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn double(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     alt((
         double_infinity,
         double_negative_infinity,
@@ -132,49 +244,52 @@ fn double(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
     ))(input)
 }
 
-fn null(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn null(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     map(tuple((char('_'), crlf)), |_| ProtocolDataType::Null)(input)
 }
 
-fn big_number(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn big_number(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     map(
-        delimited(char('('), take_until("\r\n"), crlf),
-        |number_str: &str| ProtocolDataType::BigNumber(number_str.parse().unwrap()),
+        map_res(delimited(char('('), take_until("\r\n"), crlf), number),
+        ProtocolDataType::BigNumber,
     )(input)
 }
 
-fn bulk_error_empty(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
-    map(tuple((tag("!0"), crlf)), |_| {
+fn bulk_error_empty(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    map(tuple((tag("!0"), crlf, crlf)), |_| {
         ProtocolDataType::BulkError(String::new())
     })(input)
 }
 
-fn bulk_error_with_content(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
-    let (rest, count) = map(
-        preceded(char('!'), take_while(|a: char| is_digit(a as u8))),
-        |value| u32::from_str(value).unwrap(),
-    )(input)?;
+fn bulk_error_with_content(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
+    let (rest, count) = map_res(preceded(char('!'), take_while(is_digit)), number::<u32>)(input)?;
 
-    map(delimited(crlf, take(count), crlf), |value: &str| {
-        ProtocolDataType::BulkError(value.to_string())
-    })(rest)
+    map(
+        map_res(delimited(crlf, take(count), crlf), text),
+        ProtocolDataType::BulkError,
+    )(rest)
 }
 
-fn bulk_error(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+fn bulk_error(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     alt((bulk_error_empty, bulk_error_with_content))(input)
 }
 
-pub fn data_type(input: &str) -> IResult<&str, ProtocolDataType, VerboseError<&str>> {
+pub fn data_type(input: &[u8]) -> IResult<&[u8], ProtocolDataType, VerboseError<&[u8]>> {
     alt((
         simple_string,
         simple_error,
         bulk_string,
         bulk_error,
+        verbatim_string,
         big_number,
         integer,
         boolean,
         double,
         array,
+        map_type,
+        set,
+        push,
+        attributed,
         null,
     ))(input)
 }
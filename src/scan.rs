@@ -0,0 +1,65 @@
+use std::collections::VecDeque;
+
+use crate::{client::Client, error::CamasError};
+
+/// Runs a single round of a `SCAN`-like command from a cursor, returning the
+/// next cursor and the items returned
+type Round<'a, T> = Box<dyn FnMut(&mut Client, u64) -> Result<(u64, Vec<T>), CamasError> + 'a>;
+
+/// An iterator over the results of `SCAN`, or of `HSCAN`, `SSCAN` and
+/// `ZSCAN`, fetching them in rounds as needed.
+///
+/// Every round is sent with the same options. Items added or removed while
+/// iterating may or may not be returned, and an item may be returned more
+/// than once, as documented for `SCAN`.
+///
+/// Iteration stops after the first error, which is returned as the last
+/// item.
+pub struct Scan<'a, T = String> {
+    client: &'a mut Client,
+    round: Round<'a, T>,
+    /// The cursor of the next round, which is `None` once iteration is over
+    cursor: Option<u64>,
+    /// Items returned by the last round that weren't yielded yet
+    items: VecDeque<T>,
+}
+
+impl<'a, T> Scan<'a, T> {
+    pub(crate) fn new<R>(client: &'a mut Client, round: R) -> Self
+    where
+        R: FnMut(&mut Client, u64) -> Result<(u64, Vec<T>), CamasError> + 'a,
+    {
+        Self {
+            client,
+            round: Box::new(round),
+            cursor: Some(0),
+            items: VecDeque::new(),
+        }
+    }
+}
+
+impl<T> Iterator for Scan<'_, T> {
+    type Item = Result<T, CamasError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.pop_front() {
+                return Some(Ok(item));
+            }
+
+            let cursor = self.cursor?;
+
+            match (self.round)(self.client, cursor) {
+                Ok((next_cursor, items)) => {
+                    self.cursor = Some(next_cursor).filter(|&cursor| cursor != 0);
+                    self.items.extend(items);
+                }
+                Err(error) => {
+                    self.cursor = None;
+
+                    return Some(Err(error));
+                }
+            }
+        }
+    }
+}
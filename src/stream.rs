@@ -0,0 +1,154 @@
+use std::{
+    io::{Error, ErrorKind, Read, Result, Write},
+    net::{SocketAddr, TcpStream},
+    time::Duration,
+};
+
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+
+#[cfg(feature = "tls")]
+use rustls::{ClientConnection, StreamOwned};
+
+/// A connection to Redis, over any of the supported transports
+pub(crate) enum Stream {
+    Tcp(TcpStream),
+    #[cfg(unix)]
+    Unix(UnixStream),
+    #[cfg(feature = "tls")]
+    Tls(Box<StreamOwned<ClientConnection, TcpStream>>),
+}
+
+impl Stream {
+    pub fn read_timeout(&self) -> Result<Option<Duration>> {
+        match self {
+            Stream::Tcp(stream) => stream.read_timeout(),
+            #[cfg(unix)]
+            Stream::Unix(stream) => stream.read_timeout(),
+            #[cfg(feature = "tls")]
+            Stream::Tls(stream) => stream.get_ref().read_timeout(),
+        }
+    }
+
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<()> {
+        match self {
+            Stream::Tcp(stream) => stream.set_read_timeout(timeout),
+            #[cfg(unix)]
+            Stream::Unix(stream) => stream.set_read_timeout(timeout),
+            #[cfg(feature = "tls")]
+            Stream::Tls(stream) => stream.get_ref().set_read_timeout(timeout),
+        }
+    }
+
+    pub fn write_timeout(&self) -> Result<Option<Duration>> {
+        match self {
+            Stream::Tcp(stream) => stream.write_timeout(),
+            #[cfg(unix)]
+            Stream::Unix(stream) => stream.write_timeout(),
+            #[cfg(feature = "tls")]
+            Stream::Tls(stream) => stream.get_ref().write_timeout(),
+        }
+    }
+
+    /// Opens a new connection of the same kind to the same server, with the
+    /// same timeouts
+    pub fn reopen(&self, addresses: &[SocketAddr]) -> Result<Stream> {
+        let stream = match self {
+            Stream::Tcp(_) => Stream::Tcp(connect_tcp(addresses, None)?),
+            #[cfg(unix)]
+            Stream::Unix(stream) => {
+                let address = stream.peer_addr()?;
+
+                let path = address.as_pathname().ok_or_else(|| {
+                    Error::new(ErrorKind::Unsupported, "The socket has no path to reopen")
+                })?;
+
+                Stream::Unix(UnixStream::connect(path)?)
+            }
+            #[cfg(feature = "tls")]
+            Stream::Tls(_) => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "TLS connections can't be reopened",
+                ))
+            }
+        };
+
+        stream.set_read_timeout(self.read_timeout()?)?;
+        stream.set_write_timeout(self.write_timeout()?)?;
+
+        Ok(stream)
+    }
+
+    pub fn set_write_timeout(&self, timeout: Option<Duration>) -> Result<()> {
+        match self {
+            Stream::Tcp(stream) => stream.set_write_timeout(timeout),
+            #[cfg(unix)]
+            Stream::Unix(stream) => stream.set_write_timeout(timeout),
+            #[cfg(feature = "tls")]
+            Stream::Tls(stream) => stream.get_ref().set_write_timeout(timeout),
+        }
+    }
+}
+
+/// Connects to the first of the addresses that accepts the connection,
+/// returning the error of the last one if none does
+pub(crate) fn connect_tcp(
+    addresses: &[SocketAddr],
+    timeout: Option<Duration>,
+) -> Result<TcpStream> {
+    let mut last_error = None;
+
+    for address in addresses {
+        let result = match timeout {
+            Some(timeout) => TcpStream::connect_timeout(address, timeout),
+            None => TcpStream::connect(address),
+        };
+
+        match result {
+            Ok(stream) => return Ok(stream),
+            Err(error) => last_error = Some(error),
+        }
+    }
+
+    Err(last_error.unwrap_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            "Could not resolve the given address",
+        )
+    }))
+}
+
+impl Read for Stream {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        match self {
+            Stream::Tcp(stream) => stream.read(buf),
+            #[cfg(unix)]
+            Stream::Unix(stream) => stream.read(buf),
+            #[cfg(feature = "tls")]
+            Stream::Tls(stream) => stream.read(buf),
+        }
+    }
+}
+
+impl Write for Stream {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        match self {
+            Stream::Tcp(stream) => stream.write(buf),
+            #[cfg(unix)]
+            Stream::Unix(stream) => stream.write(buf),
+            #[cfg(feature = "tls")]
+            Stream::Tls(stream) => stream.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        match self {
+            Stream::Tcp(stream) => stream.flush(),
+            #[cfg(unix)]
+            Stream::Unix(stream) => stream.flush(),
+            #[cfg(feature = "tls")]
+            Stream::Tls(stream) => stream.flush(),
+        }
+    }
+}
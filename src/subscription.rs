@@ -0,0 +1,199 @@
+use crate::{
+    client::Client,
+    commands::{subscribe::SubscribeArguments, Command},
+    data_type::DataType,
+    error::CamasError,
+    from_redis_value::FromRedisValue,
+    protocol::ProtocolDataType,
+};
+
+/// An event received by a subscribed connection
+#[derive(Debug, PartialEq)]
+pub enum Message {
+    /// The connection subscribed to a channel. `count` is the number of
+    /// channels it's subscribed to now.
+    Subscribed { channel: String, count: u64 },
+    /// The connection unsubscribed from a channel. `count` is the number of
+    /// channels it's still subscribed to.
+    Unsubscribed { channel: Option<String>, count: u64 },
+    /// A message was published to a channel the connection is subscribed
+    /// to. Payloads that aren't valid UTF-8 come as [`DataType::Bytes`].
+    Published { channel: String, payload: DataType },
+}
+
+impl Message {
+    /// Decodes a pub/sub event, which Redis sends as an array (or a push,
+    /// under RESP3) starting with the kind of the event
+    pub(crate) fn parse(reply: &ProtocolDataType) -> Result<Self, CamasError> {
+        let unexpected_reply = || CamasError::UnexpectedReply(reply.clone());
+
+        let (ProtocolDataType::Array(items) | ProtocolDataType::Push(items)) = reply else {
+            return Err(unexpected_reply());
+        };
+
+        let [kind, channel, data] = items.as_slice() else {
+            return Err(unexpected_reply());
+        };
+
+        match String::from_redis_value(kind)?.as_str() {
+            "subscribe" => Ok(Message::Subscribed {
+                channel: String::from_redis_value(channel)?,
+                count: i64::from_redis_value(data)? as u64,
+            }),
+            "unsubscribe" => Ok(Message::Unsubscribed {
+                channel: Option::<String>::from_redis_value(channel)?,
+                count: i64::from_redis_value(data)? as u64,
+            }),
+            "message" => Ok(Message::Published {
+                channel: String::from_redis_value(channel)?,
+                payload: data.try_into()?,
+            }),
+            _ => Err(unexpected_reply()),
+        }
+    }
+}
+
+/// A connection subscribed to pub/sub channels.
+///
+/// While subscribed, a connection can only receive messages and (un)subscribe
+/// from channels, so it can't be used as a regular client.
+pub struct Subscription {
+    client: Client,
+}
+
+impl Subscription {
+    pub(crate) fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Subscribes to more channels.
+    ///
+    /// The confirmations arrive as `Message::Subscribed` events.
+    pub fn subscribe(&mut self, channels: &[&str]) -> Result<(), CamasError> {
+        self.client
+            .write_commands(&[Command::Subscribe(SubscribeArguments::new(channels))])
+    }
+
+    /// Unsubscribes from the given channels, or from all of them if none is
+    /// given.
+    ///
+    /// The confirmations arrive as `Message::Unsubscribed` events.
+    pub fn unsubscribe(&mut self, channels: &[&str]) -> Result<(), CamasError> {
+        self.client
+            .write_commands(&[Command::Unsubscribe(SubscribeArguments::new(channels))])
+    }
+
+    /// Waits for the next event on the subscribed channels.
+    pub fn next_message(&mut self) -> Result<Message, CamasError> {
+        let reply = self.client.read_replies(1)?.remove(0);
+
+        Message::parse(&reply.without_attributes())
+    }
+}
+
+#[cfg(test)]
+mod message {
+    use std::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn parses_subscribe_confirmation() -> Result<(), Box<dyn Error>> {
+        let reply = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("subscribe".into()),
+            ProtocolDataType::BulkString("news".into()),
+            ProtocolDataType::Integer(1),
+        ]);
+
+        assert_eq!(
+            Message::parse(&reply)?,
+            Message::Subscribed {
+                channel: "news".into(),
+                count: 1
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_unsubscribe_confirmation_without_channel() -> Result<(), Box<dyn Error>> {
+        let reply = ProtocolDataType::Push(vec![
+            ProtocolDataType::BulkString("unsubscribe".into()),
+            ProtocolDataType::Null,
+            ProtocolDataType::Integer(0),
+        ]);
+
+        assert_eq!(
+            Message::parse(&reply)?,
+            Message::Unsubscribed {
+                channel: None,
+                count: 0
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_published_message() -> Result<(), Box<dyn Error>> {
+        let reply = ProtocolDataType::Push(vec![
+            ProtocolDataType::BulkString("message".into()),
+            ProtocolDataType::BulkString("news".into()),
+            ProtocolDataType::BulkString("Hello".into()),
+        ]);
+
+        assert_eq!(
+            Message::parse(&reply)?,
+            Message::Published {
+                channel: "news".into(),
+                payload: DataType::String("Hello".into())
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parses_published_binary_message() -> Result<(), Box<dyn Error>> {
+        let reply = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("message".into()),
+            ProtocolDataType::BulkString("news".into()),
+            ProtocolDataType::BulkBytes(vec![0xff, 0x00]),
+        ]);
+
+        assert_eq!(
+            Message::parse(&reply)?,
+            Message::Published {
+                channel: "news".into(),
+                payload: DataType::Bytes(vec![0xff, 0x00])
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_to_parse_unknown_event() {
+        let reply = ProtocolDataType::Array(vec![
+            ProtocolDataType::BulkString("pmessage".into()),
+            ProtocolDataType::BulkString("news".into()),
+            ProtocolDataType::BulkString("Hello".into()),
+        ]);
+
+        assert!(matches!(
+            Message::parse(&reply),
+            Err(CamasError::UnexpectedReply(_))
+        ));
+    }
+
+    #[test]
+    fn fails_to_parse_non_array_reply() {
+        let reply = ProtocolDataType::SimpleString("OK".into());
+
+        assert!(matches!(
+            Message::parse(&reply),
+            Err(CamasError::UnexpectedReply(_))
+        ));
+    }
+}
@@ -1,15 +1,339 @@
-use std::error::Error;
+use std::{
+    error::Error,
+    io::{Read, Write},
+    net::{SocketAddr, TcpListener},
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
 
 use camas::{
-    commands::set::{SetOptions, SetResponse},
+    client::Client,
+    commands::{
+        expire::ExpireCondition,
+        scan::{KeyScanOptions, ScanOptions, ScanOptionsBuilder},
+        set::{ExpirationTime, SetMode, SetOptions, SetResponse},
+        ttl::TtlResult,
+        type_::KeyType,
+    },
     data_type::DataType,
+    error::CamasError,
+    observer::Observer,
+    pool::Pool,
+    protocol::ProtocolDataType,
+    subscription::Message,
 };
+use serial_test::serial;
 
 use crate::common::{setup, teardown};
 
 mod common;
 
 #[test]
+fn connect_timeout_with_unreachable_address_fails_within_timeout() {
+    let timeout = Duration::from_millis(200);
+
+    let started_at = Instant::now();
+
+    let result = Client::connect_timeout("192.0.2.1:6379", timeout);
+
+    assert!(result.is_err());
+    assert!(started_at.elapsed() < timeout * 5);
+}
+
+#[test]
+fn connect_with_many_addresses_falls_back_to_the_next_one() -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+
+    // Nothing listens on the first address, so its connection is refused
+    let addresses = [
+        SocketAddr::from(([127, 0, 0, 1], 1)),
+        listener.local_addr()?,
+    ];
+
+    let result = Client::connect(&addresses[..]);
+
+    assert!(result.is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn connect_with_only_bad_addresses_returns_last_error() {
+    let addresses = [
+        SocketAddr::from(([127, 0, 0, 1], 1)),
+        SocketAddr::from(([127, 0, 0, 1], 2)),
+    ];
+
+    let result = Client::connect(&addresses[..]);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn read_timeout_with_absent_reply_returns_timeout_error() -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+
+    let mut client = Client::connect(listener.local_addr()?)?;
+
+    client.set_read_timeout(Some(Duration::from_millis(100)))?;
+
+    let result = client.get("foo");
+
+    assert_eq!(
+        "Timed out while waiting for a reply from Redis",
+        result.unwrap_err().to_string()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn connect_with_reconnect_retries_command_after_connection_drops() -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+
+    let mut client = Client::connect_with_reconnect(listener.local_addr()?)?;
+
+    // Drops the first connection right away and replies on the next one
+    let server = thread::spawn(move || -> std::io::Result<()> {
+        drop(listener.accept()?);
+
+        let (mut stream, _) = listener.accept()?;
+
+        let mut buf = [0u8; 1024];
+        let bytes_read = stream.read(&mut buf)?;
+
+        assert!(bytes_read > 0);
+
+        stream.write_all(b"$5\r\nHello\r\n")
+    });
+
+    let expected = Some(DataType::String("Hello".into()));
+
+    let result = client.get("foo")?;
+
+    assert_eq!(expected, result);
+
+    server.join().expect("Server thread panicked")?;
+
+    Ok(())
+}
+
+#[test]
+fn reconnect_keeps_read_timeout() -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+
+    let mut client = Client::connect_with_reconnect(listener.local_addr()?)?;
+
+    client.set_read_timeout(Some(Duration::from_millis(100)))?;
+
+    // Drops the first connection right away and never replies on the next one
+    let server = thread::spawn(move || -> std::io::Result<()> {
+        drop(listener.accept()?);
+
+        let (mut stream, _) = listener.accept()?;
+
+        let mut buf = [0u8; 1024];
+
+        while stream.read(&mut buf)? > 0 {}
+
+        Ok(())
+    });
+
+    let result = client.get("foo");
+
+    assert_eq!(
+        "Timed out while waiting for a reply from Redis",
+        result.unwrap_err().to_string()
+    );
+
+    drop(client);
+
+    server.join().unwrap()?;
+
+    Ok(())
+}
+
+#[test]
+fn del_with_unexpected_reply_returns_error() -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+
+    let mut client = Client::connect(listener.local_addr()?)?;
+
+    // Replies to a DEL with a bulk string, as a desynced connection would
+    let server = thread::spawn(move || -> std::io::Result<()> {
+        let (mut stream, _) = listener.accept()?;
+
+        let mut buf = [0u8; 1024];
+        let bytes_read = stream.read(&mut buf)?;
+
+        assert!(bytes_read > 0);
+
+        stream.write_all(b"$5\r\nHello\r\n")
+    });
+
+    let result = client.del(&["foo"]);
+
+    assert!(matches!(result, Err(CamasError::UnexpectedReply(_))));
+
+    server.join().expect("Server thread panicked")?;
+
+    Ok(())
+}
+
+#[test]
+fn commands_with_unexpected_replies_return_errors() -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+
+    let mut client = Client::connect(listener.local_addr()?)?;
+
+    // Replies to every command with a bulk string, as a desynced connection
+    // would
+    let server = thread::spawn(move || -> std::io::Result<()> {
+        let (mut stream, _) = listener.accept()?;
+
+        let mut buf = [0u8; 1024];
+
+        while stream.read(&mut buf)? > 0 {
+            stream.write_all(b"$5\r\nHello\r\n")?;
+        }
+
+        Ok(())
+    });
+
+    assert!(matches!(
+        client.expire("foo", 10, None),
+        Err(CamasError::UnexpectedReply(_))
+    ));
+    assert!(matches!(
+        client.incr("foo"),
+        Err(CamasError::UnexpectedReply(_))
+    ));
+    assert!(matches!(
+        client.rename("foo", "bar"),
+        Err(CamasError::UnexpectedReply(_))
+    ));
+    assert!(matches!(
+        client.select(1),
+        Err(CamasError::UnexpectedReply(_))
+    ));
+
+    drop(client);
+
+    server.join().expect("Server thread panicked")?;
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn connect_unix_sends_commands_over_the_socket() -> Result<(), Box<dyn Error>> {
+    use std::os::unix::net::UnixListener;
+
+    let path = std::env::temp_dir().join(format!("camas-{}.sock", std::process::id()));
+
+    let listener = UnixListener::bind(&path)?;
+
+    // Replies to a SET and then to a GET, as Redis would
+    let server = thread::spawn(move || -> std::io::Result<()> {
+        let (mut stream, _) = listener.accept()?;
+
+        for reply in [&b"+OK\r\n"[..], b"$5\r\nHello\r\n"] {
+            let mut buf = [0u8; 1024];
+            let bytes_read = stream.read(&mut buf)?;
+
+            assert!(bytes_read > 0);
+
+            stream.write_all(reply)?;
+        }
+
+        Ok(())
+    });
+
+    let mut client = Client::connect_unix(&path)?;
+
+    client.set("foo", "Hello", Default::default())?;
+
+    let expected = Some(DataType::String("Hello".into()));
+
+    let result = client.get("foo")?;
+
+    assert_eq!(expected, result);
+
+    server.join().expect("Server thread panicked")?;
+
+    std::fs::remove_file(path)?;
+
+    Ok(())
+}
+
+#[cfg(feature = "tls")]
+#[test]
+fn connect_tls_with_custom_roots_sends_commands_over_tls() -> Result<(), Box<dyn Error>> {
+    use std::sync::Arc;
+
+    use camas::rustls::{
+        self,
+        pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer},
+        RootCertStore, ServerConfig, ServerConnection, StreamOwned,
+    };
+
+    let certificate_authority =
+        CertificateDer::from_pem_file(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/tls/ca.pem"))?;
+    let certificate = CertificateDer::from_pem_file(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/tls/server.pem"
+    ))?;
+    let key =
+        PrivateKeyDer::from_pem_file(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/tls/server.key"))?;
+
+    let config =
+        ServerConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+            .with_safe_default_protocol_versions()?
+            .with_no_client_auth()
+            .with_single_cert(vec![certificate], key)?;
+
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let address = listener.local_addr()?;
+
+    // Replies to a GET over TLS, as Redis would
+    let server = thread::spawn(move || -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (stream, _) = listener.accept()?;
+
+        let mut stream = StreamOwned::new(ServerConnection::new(Arc::new(config))?, stream);
+
+        let mut buf = [0u8; 1024];
+        let bytes_read = stream.read(&mut buf)?;
+
+        assert!(bytes_read > 0);
+
+        stream.write_all(b"$5\r\nHello\r\n")?;
+        stream.flush()?;
+
+        Ok(())
+    });
+
+    let mut roots = RootCertStore::empty();
+    roots.add(certificate_authority)?;
+
+    let mut client = Client::connect_tls_with_roots(address, "localhost", roots)?;
+
+    let expected = Some(DataType::String("Hello".into()));
+
+    let result = client.get("foo")?;
+
+    assert_eq!(expected, result);
+
+    server
+        .join()
+        .expect("Server thread panicked")
+        .map_err(|err| err.to_string())?;
+
+    Ok(())
+}
+
+#[test]
+#[serial]
 fn set_with_default_options_returns_ok() -> Result<(), Box<dyn Error>> {
     let mut client = setup()?;
 
@@ -23,14 +347,18 @@ fn set_with_default_options_returns_ok() -> Result<(), Box<dyn Error>> {
 }
 
 #[test]
+#[serial]
 fn set_with_get_option_returns_previous_value_when_key_was_set() -> Result<(), Box<dyn Error>> {
     let mut client = setup()?;
 
-    let mut options = SetOptions::default();
+    client.set("foo", "bar", Default::default())?;
 
-    options.get_previous_value = true;
+    let options = SetOptions {
+        get_previous_value: true,
+        ..Default::default()
+    };
 
-    let result = client.set("foo", "bar", options)?;
+    let result = client.set("foo", "baz", options)?;
 
     let expected = SetResponse::PreviousValue(Some(DataType::String("bar".into())));
 
@@ -40,13 +368,15 @@ fn set_with_get_option_returns_previous_value_when_key_was_set() -> Result<(), B
 }
 
 #[test]
+#[serial]
 fn set_with_get_option_returns_no_previous_value_when_key_was_not_set() -> Result<(), Box<dyn Error>>
 {
     let mut client = setup()?;
 
-    let mut options = SetOptions::default();
-
-    options.get_previous_value = true;
+    let options = SetOptions {
+        get_previous_value: true,
+        ..Default::default()
+    };
 
     let result = client.set("foo", "bar", options)?;
 
@@ -58,6 +388,7 @@ fn set_with_get_option_returns_no_previous_value_when_key_was_not_set() -> Resul
 }
 
 #[test]
+#[serial]
 fn get_with_existent_key_returns_stored_value() -> Result<(), Box<dyn Error>> {
     let mut client = setup()?;
 
@@ -73,14 +404,2335 @@ fn get_with_existent_key_returns_stored_value() -> Result<(), Box<dyn Error>> {
 }
 
 #[test]
+#[serial]
 fn get_with_non_existent_key_returns_none() -> Result<(), Box<dyn Error>> {
     let mut client = setup()?;
 
     client.set("foo", "bar", Default::default())?;
 
-    let result = client.get("foo")?;
+    let result = client.get("qux")?;
 
     assert_eq!(None, result);
 
     teardown(client)
 }
+
+#[test]
+#[serial]
+fn get_with_value_larger_than_receive_buffer_returns_stored_value() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let value = "camas".repeat(1024);
+
+    client.set("foo", value.as_str(), Default::default())?;
+
+    let expected = Some(DataType::String(value));
+
+    let result = client.get("foo")?;
+
+    assert_eq!(expected, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn get_with_binary_value_returns_stored_bytes() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let value = [b'c', 0xFF, 0x00, b'a'];
+
+    client.set_bytes("foo", &value, Default::default())?;
+
+    let expected = Some(DataType::Bytes(value.to_vec()));
+
+    let result = client.get("foo")?;
+
+    assert_eq!(expected, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn pipeline_with_many_commands_returns_all_replies_in_order() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let mut pipeline = client.pipeline();
+
+    for index in 0..50 {
+        pipeline.set(format!("key:{}", index), index, Default::default());
+    }
+
+    for index in 0..50 {
+        pipeline.get(format!("key:{}", index));
+    }
+
+    let expected = (0..50)
+        .map(|_| ProtocolDataType::SimpleString("OK".into()))
+        .chain((0..50).map(|index| ProtocolDataType::BulkString(index.to_string())))
+        .collect::<Vec<_>>();
+
+    let result = pipeline.execute()?;
+
+    assert_eq!(expected, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn expire_with_existent_key_returns_true() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    let result = client.expire("foo", 10, None)?;
+
+    assert!(result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn expire_with_unmet_condition_returns_false() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    let result = client.expire("foo", 10, Some(ExpireCondition::Xx))?;
+
+    assert!(!result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn ttl_with_expiring_key_returns_remaining_time() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+    client.expire("foo", 100, None)?;
+
+    let result = client.ttl("foo")?;
+
+    assert!(matches!(result, TtlResult::Expires(ttl) if ttl > 0 && ttl <= 100));
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn pttl_with_non_existent_key_returns_no_key() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.pttl("foo")?;
+
+    assert_eq!(TtlResult::NoKey, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn incr_with_integer_value_returns_incremented_value() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("counter", 10, Default::default())?;
+
+    let result = client.incr("counter")?;
+
+    assert_eq!(11, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn decr_with_non_existent_key_returns_minus_one() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.decr("counter")?;
+
+    assert_eq!(-1, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn incr_with_non_integer_value_returns_error() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("counter", "bar", Default::default())?;
+
+    let result = client.incr("counter");
+
+    assert!(matches!(result, Err(CamasError::Server { code, .. }) if code == "ERR"));
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn incr_by_with_integer_value_returns_incremented_value() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("counter", 10, Default::default())?;
+
+    let result = client.incr_by("counter", 5)?;
+
+    assert_eq!(15, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn decr_by_with_integer_value_returns_decremented_value() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("counter", 10, Default::default())?;
+
+    let result = client.decr_by("counter", 15)?;
+
+    assert_eq!(-5, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn incr_by_float_with_exponential_delta_returns_incremented_value() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("counter", 10.5, Default::default())?;
+
+    let result = client.incr_by_float("counter", 3.0e3)?;
+
+    assert_eq!(3010.5, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn mget_with_existent_and_non_existent_keys_returns_values_in_order() -> Result<(), Box<dyn Error>>
+{
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+    client.set("baz", "qux", Default::default())?;
+
+    let expected = vec![
+        Some(DataType::String("qux".into())),
+        None,
+        Some(DataType::String("bar".into())),
+    ];
+
+    let result = client.mget(&["baz", "quux", "foo"])?;
+
+    assert_eq!(expected, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn append_returns_cumulative_length() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let first_result = client.append("foo", "bar")?;
+    let second_result = client.append("foo", "baz")?;
+
+    assert_eq!(3, first_result);
+    assert_eq!(6, second_result);
+    assert_eq!(Some(DataType::String("barbaz".into())), client.get("foo")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn getdel_with_existent_key_returns_stored_value_and_deletes_key() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    let expected = Some(DataType::String("bar".into()));
+
+    let result = client.getdel("foo")?;
+
+    assert_eq!(expected, result);
+    assert_eq!(None, client.get("foo")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn getdel_with_non_existent_key_returns_none() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.getdel("foo")?;
+
+    assert_eq!(None, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn getex_with_expiration_time_sets_ttl() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    let result = client.getex("foo", Some(ExpirationTime::Seconds(100)))?;
+
+    assert_eq!(Some(DataType::String("bar".into())), result);
+    assert!(matches!(client.ttl("foo")?, TtlResult::Expires(_)));
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn getex_persist_removes_ttl() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+    client.expire("foo", 100, None)?;
+
+    let result = client.getex_persist("foo")?;
+
+    assert_eq!(Some(DataType::String("bar".into())), result);
+    assert_eq!(TtlResult::NoExpiry, client.ttl("foo")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn keys_with_pattern_returns_matching_keys() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("user:1", "foo", Default::default())?;
+    client.set("user:2", "bar", Default::default())?;
+    client.set("other", "baz", Default::default())?;
+
+    let mut result = client.keys("user:*")?;
+
+    result.sort();
+
+    assert_eq!(vec!["user:1", "user:2"], result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn key_type_with_string_value_returns_string() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    let result = client.key_type("foo")?;
+
+    assert_eq!(KeyType::String, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn rename_with_existent_key_moves_value() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    client.rename("foo", "baz")?;
+
+    assert_eq!(None, client.get("foo")?);
+    assert_eq!(Some(DataType::String("bar".into())), client.get("baz")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn rename_with_non_existent_key_returns_error() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.rename("foo", "baz");
+
+    assert!(result.is_err());
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn rename_nx_with_existent_destination_returns_false() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+    client.set("baz", "qux", Default::default())?;
+
+    let result = client.rename_nx("foo", "baz")?;
+
+    assert!(!result);
+    assert_eq!(Some(DataType::String("qux".into())), client.get("baz")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn select_isolates_keys_between_databases() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.select(1)?;
+    client.set("foo", "bar", Default::default())?;
+    client.select(0)?;
+
+    let result = client.get("foo")?;
+
+    assert_eq!(None, result);
+
+    client.select(1)?;
+    client.flushdb(false)?;
+    client.select(0)?;
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn connect_with_auth_with_correct_password_allows_commands() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::connect_with_auth("localhost:6380", None, "camas")?;
+
+    let result = client.get("foo")?;
+
+    assert_eq!(None, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn connect_with_auth_with_wrong_password_returns_error() {
+    let result = Client::connect_with_auth("localhost:6380", None, "wrong");
+
+    assert!(result.is_err());
+}
+
+#[test]
+#[serial]
+fn auth_after_connecting_allows_commands() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::connect("localhost:6380")?;
+
+    assert!(client.get("foo").is_err());
+
+    client.auth(None, "camas")?;
+
+    assert_eq!(None, client.get("foo")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn connect_url_with_credentials_and_database_authenticates_and_selects(
+) -> Result<(), Box<dyn Error>> {
+    let mut client = Client::connect_url("redis://:camas@localhost:6380/2")?;
+
+    assert_eq!(2, client.database());
+    assert_eq!(None, client.get("foo")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn hello_with_resp2_returns_server_information() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.hello(2)?;
+
+    assert_eq!("redis", result.server);
+    assert_eq!(2, result.proto);
+    assert!(!result.version.is_empty());
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn hello_with_resp3_returns_server_information() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.hello(3)?;
+
+    assert_eq!("redis", result.server);
+    assert_eq!(3, result.proto);
+
+    client.set("foo", "bar", Default::default())?;
+
+    assert_eq!(Some(DataType::String("bar".into())), client.get("foo")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn command_with_dbsize_returns_integer_reply() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    let result = client.command(&["DBSIZE"])?;
+
+    assert_eq!(ProtocolDataType::Integer(1), result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn command_without_arguments_returns_error() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.command::<&str>(&[]);
+
+    assert!(matches!(result, Err(CamasError::InvalidArgument(_))));
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn rpush_with_one_value_at_a_time_returns_growing_length() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let lengths = ["a", "b", "c"]
+        .iter()
+        .map(|value| client.rpush("list", &[value]))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(vec![1, 2, 3], lengths);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn lpush_with_many_values_returns_list_length() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.rpush("list", &["a"])?;
+
+    let result = client.lpush("list", &["b", "c"])?;
+
+    assert_eq!(3, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn lpop_without_count_returns_first_value() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.rpush("list", &["a", "b", "c"])?;
+
+    let result = client.lpop("list", None)?;
+
+    assert_eq!(Some(vec![DataType::String("a".into())]), result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn rpop_with_count_returns_last_values() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.rpush("list", &["a", "b", "c"])?;
+
+    let result = client.rpop("list", Some(2))?;
+
+    assert_eq!(
+        Some(vec![
+            DataType::String("c".into()),
+            DataType::String("b".into())
+        ]),
+        result
+    );
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn lpop_with_non_existent_key_returns_none() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.lpop("list", None)?;
+
+    assert_eq!(None, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn lrange_with_negative_stop_returns_values_until_the_end() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.rpush("list", &["a", "b", "c", "d"])?;
+
+    let result = client.lrange("list", 1, -1)?;
+
+    assert_eq!(vec!["b", "c", "d"], result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn llen_with_three_values_returns_three() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.rpush("list", &["a", "b", "c"])?;
+
+    let result = client.llen("list")?;
+
+    assert_eq!(3, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn lindex_with_negative_index_returns_last_value() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.rpush("list", &["a", "b", "c"])?;
+
+    let result = client.lindex("list", -1)?;
+
+    assert_eq!(Some(DataType::String("c".into())), result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn lindex_with_out_of_range_index_returns_none() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.rpush("list", &["a", "b", "c"])?;
+
+    let result = client.lindex("list", 99)?;
+
+    assert_eq!(None, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn hset_with_new_field_returns_one() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.hset("user", "name", "John")?;
+
+    assert_eq!(1, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn hset_with_existing_field_returns_zero_and_overwrites_it() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.hset("user", "name", "John")?;
+
+    let result = client.hset("user", "name", "Jane")?;
+
+    assert_eq!(0, result);
+    assert_eq!(
+        Some(DataType::String("Jane".into())),
+        client.hget("user", "name")?
+    );
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn hget_with_missing_field_returns_none() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.hset("user", "name", "John")?;
+
+    let result = client.hget("user", "age")?;
+
+    assert_eq!(None, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn sadd_with_duplicate_members_only_counts_new_ones() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.sadd("set", &["a", "b"])?;
+
+    let result = client.sadd("set", &["b", "c", "c"])?;
+
+    let mut members = client.smembers("set")?;
+    members.sort();
+
+    assert_eq!(1, result);
+    assert_eq!(vec!["a", "b", "c"], members);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn smembers_with_non_existent_key_returns_empty_vector() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.smembers("set")?;
+
+    assert!(result.is_empty());
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn scard_with_three_members_returns_three() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.sadd("set", &["a", "b", "c"])?;
+
+    let result = client.scard("set")?;
+
+    assert_eq!(3, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn sismember_with_present_member_returns_true() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.sadd("set", &["a", "b", "c"])?;
+
+    let result = client.sismember("set", "b")?;
+
+    assert!(result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn sismember_with_absent_member_returns_false() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.sadd("set", &["a", "b", "c"])?;
+
+    let result = client.sismember("set", "d")?;
+
+    assert!(!result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn srem_with_present_and_absent_members_returns_removed_count() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.sadd("set", &["a", "b", "c"])?;
+
+    let result = client.srem("set", &["a", "c", "d"])?;
+
+    assert_eq!(2, result);
+    assert_eq!(vec!["b"], client.smembers("set")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn spop_without_count_returns_one_member() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.sadd("set", &["a", "b", "c"])?;
+
+    let result = client.spop("set", None)?;
+
+    assert_eq!(1, result.len());
+    assert!(!client.sismember("set", result[0].as_str().unwrap())?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn spop_with_count_returns_many_members() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.sadd("set", &["a", "b", "c"])?;
+
+    let mut result = client
+        .spop("set", Some(3))?
+        .into_iter()
+        .filter_map(DataType::into_string)
+        .collect::<Vec<_>>();
+    result.sort();
+
+    assert_eq!(vec!["a", "b", "c"], result);
+    assert_eq!(0, client.scard("set")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn spop_with_non_existent_key_returns_empty_vector() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.spop("set", None)?;
+
+    assert!(result.is_empty());
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn zadd_with_new_and_existing_members_returns_added_count() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.zadd("zset", &[(1.0, "a")])?;
+
+    let result = client.zadd("zset", &[(1.5, "a"), (2.0, "b")])?;
+
+    assert_eq!(1, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn zrange_without_scores_returns_members_by_score() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.zadd("zset", &[(3.0, "c"), (1.0, "a"), (2.5, "b")])?;
+
+    let result = client.zrange("zset", 0, -1, false)?;
+
+    assert_eq!(
+        vec![
+            ("a".to_string(), None),
+            ("b".to_string(), None),
+            ("c".to_string(), None)
+        ],
+        result
+    );
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn zrange_with_scores_returns_members_paired_with_scores() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.zadd("zset", &[(3.0, "c"), (1.0, "a"), (2.5, "b")])?;
+
+    let result = client.zrange("zset", 1, -1, true)?;
+
+    assert_eq!(
+        vec![("b".to_string(), Some(2.5)), ("c".to_string(), Some(3.0))],
+        result
+    );
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn zscore_with_present_member_returns_its_score() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.zadd("zset", &[(1.0, "a"), (2.5, "b")])?;
+
+    let result = client.zscore("zset", "b")?;
+
+    assert_eq!(Some(2.5), result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn zscore_with_absent_member_returns_none() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.zadd("zset", &[(1.0, "a"), (2.5, "b")])?;
+
+    let result = client.zscore("zset", "c")?;
+
+    assert_eq!(None, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn zrank_with_present_member_returns_its_index() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.zadd("zset", &[(3.0, "c"), (1.0, "a"), (2.5, "b")])?;
+
+    let result = client.zrank("zset", "c")?;
+
+    assert_eq!(Some(2), result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn zrank_with_absent_member_returns_none() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.zadd("zset", &[(1.0, "a")])?;
+
+    let result = client.zrank("zset", "b")?;
+
+    assert_eq!(None, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn subscribe_receives_message_published_by_another_client() -> Result<(), Box<dyn Error>> {
+    let mut publisher = setup()?;
+
+    let mut subscription = Client::connect("localhost:6379")?.subscribe(&["news"])?;
+
+    assert_eq!(
+        Message::Subscribed {
+            channel: "news".into(),
+            count: 1
+        },
+        subscription.next_message()?
+    );
+
+    let receivers = publisher.command(&["PUBLISH", "news", "Hello"])?;
+
+    assert_eq!(ProtocolDataType::Integer(1), receivers);
+    assert_eq!(
+        Message::Published {
+            channel: "news".into(),
+            payload: DataType::String("Hello".into())
+        },
+        subscription.next_message()?
+    );
+
+    teardown(publisher)
+}
+
+#[test]
+#[serial]
+fn unsubscribe_confirms_each_channel_left() -> Result<(), Box<dyn Error>> {
+    let mut subscription = Client::connect("localhost:6379")?.subscribe(&["news", "sports"])?;
+
+    subscription.next_message()?;
+    subscription.next_message()?;
+
+    subscription.unsubscribe(&["news"])?;
+
+    assert_eq!(
+        Message::Unsubscribed {
+            channel: Some("news".into()),
+            count: 1
+        },
+        subscription.next_message()?
+    );
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn transaction_runs_all_commands_and_returns_their_replies() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let mut transaction = client.transaction();
+
+    transaction.set("foo", "bar", Default::default()).get("foo");
+
+    let result = transaction.execute()?;
+
+    assert_eq!(
+        vec![
+            ProtocolDataType::SimpleString("OK".into()),
+            ProtocolDataType::BulkString("bar".into())
+        ],
+        result
+    );
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn transaction_with_watched_key_changed_by_another_client_is_aborted() -> Result<(), Box<dyn Error>>
+{
+    let mut client = setup()?;
+    let mut other_client = Client::connect("localhost:6379")?;
+
+    client.set("balance", 10, Default::default())?;
+
+    client.watch(&["balance"])?;
+
+    other_client.set("balance", 20, Default::default())?;
+
+    let mut transaction = client.transaction();
+
+    transaction.incr("balance");
+
+    let result = transaction.execute();
+
+    assert!(matches!(result, Err(CamasError::TransactionAborted)));
+    assert_eq!(Some(DataType::String("20".into())), client.get("balance")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn transaction_after_unwatch_is_not_aborted() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+    let mut other_client = Client::connect("localhost:6379")?;
+
+    client.set("balance", 10, Default::default())?;
+
+    client.watch(&["balance"])?;
+    client.unwatch()?;
+
+    other_client.set("balance", 20, Default::default())?;
+
+    let mut transaction = client.transaction();
+
+    transaction.incr("balance");
+
+    let result = transaction.execute()?;
+
+    assert_eq!(vec![ProtocolDataType::Integer(21)], result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn eval_with_keys_and_arguments_runs_script() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.eval(
+        "return redis.call('set', KEYS[1], ARGV[1])",
+        &["foo"],
+        &["bar"],
+    )?;
+
+    assert_eq!(ProtocolDataType::SimpleString("OK".into()), result);
+    assert_eq!(Some(DataType::String("bar".into())), client.get("foo")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn evalsha_with_loaded_script_runs_it() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let hash = client.script_load("return redis.call('set', KEYS[1], ARGV[1])")?;
+
+    let result = client.evalsha(&hash, &["foo"], &["bar"])?;
+
+    assert_eq!(40, hash.len());
+    assert_eq!(ProtocolDataType::SimpleString("OK".into()), result);
+    assert_eq!(Some(DataType::String("bar".into())), client.get("foo")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn evalsha_with_unknown_hash_returns_noscript_error() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.evalsha("0000000000000000000000000000000000000000", &[], &[]);
+
+    assert!(matches!(result, Err(CamasError::Server { code, .. }) if code == "NOSCRIPT"));
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn config_get_with_exact_parameter_returns_one_pair() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.config_get("maxmemory")?;
+
+    assert_eq!(1, result.len());
+    assert_eq!("maxmemory", result[0].0);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn config_set_changes_parameter_value() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.config_set("maxmemory-policy", "noeviction")?;
+
+    let result = client.config_get("maxmemory-policy")?;
+
+    assert_eq!(
+        vec![("maxmemory-policy".to_string(), "noeviction".to_string())],
+        result
+    );
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn info_with_server_section_returns_only_that_section() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.info(Some("server"))?;
+
+    assert!(result.get("server", "redis_version").is_some());
+    assert_eq!(None, result.section("clients"));
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn expire_at_with_past_timestamp_deletes_key() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    let result = client.expire_at("foo", 1, None)?;
+
+    assert!(result);
+    assert_eq!(None, client.get("foo")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn pexpire_at_with_past_timestamp_deletes_key() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    let result = client.pexpire_at("foo", 1000, None)?;
+
+    assert!(result);
+    assert_eq!(None, client.get("foo")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn expire_at_with_future_timestamp_sets_ttl() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?;
+
+    client.expire_at("foo", now.as_secs() as i64 + 100, None)?;
+
+    assert!(matches!(client.ttl("foo")?, TtlResult::Expires(seconds) if seconds > 90));
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn expire_at_with_unmet_condition_returns_false() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    let result = client.expire_at("foo", 4102444800, Some(ExpireCondition::Xx))?;
+
+    assert!(!result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn persist_with_expiring_key_removes_its_timeout() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let options = SetOptions {
+        expiration_time: Some(ExpirationTime::Seconds(100)),
+        ..Default::default()
+    };
+
+    client.set("foo", "bar", options)?;
+
+    let result = client.persist("foo")?;
+
+    assert!(result);
+    assert!(matches!(client.ttl("foo")?, TtlResult::NoExpiry));
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn copy_with_string_key_duplicates_value() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    let result = client.copy("foo", "baz", false, None)?;
+
+    assert!(result);
+    assert_eq!(Some(DataType::String("bar".into())), client.get("foo")?);
+    assert_eq!(Some(DataType::String("bar".into())), client.get("baz")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn unlink_with_some_existent_keys_returns_deleted_count() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+    client.set("baz", "qux", Default::default())?;
+
+    let result = client.unlink(&["foo", "quux", "baz"])?;
+
+    assert_eq!(2, result);
+    assert_eq!(None, client.get("foo")?);
+    assert_eq!(None, client.get("baz")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn touch_with_some_existent_keys_returns_existent_count() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+    client.set("baz", "qux", Default::default())?;
+
+    let result = client.touch(&["foo", "baz", "quux"])?;
+
+    assert_eq!(2, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn randomkey_returns_none_on_empty_db_and_the_only_key_otherwise() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.flushdb(false)?;
+
+    assert_eq!(None, client.randomkey()?);
+
+    client.set("foo", "bar", Default::default())?;
+
+    assert_eq!(Some(String::from("foo")), client.randomkey()?);
+
+    teardown(client)
+}
+
+#[test]
+fn randomkey_decodes_binary_names_lossily() -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+
+    let mut client = Client::connect(listener.local_addr()?)?;
+
+    let server = thread::spawn(move || -> std::io::Result<()> {
+        let (mut stream, _) = listener.accept()?;
+
+        let mut buf = [0u8; 1024];
+
+        while stream.read(&mut buf)? > 0 {
+            stream.write_all(b"$2\r\n\xff\x00\r\n")?;
+        }
+
+        Ok(())
+    });
+
+    assert_eq!(Some(String::from("\u{fffd}\0")), client.randomkey()?);
+
+    drop(client);
+
+    server.join().expect("Server thread panicked")?;
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn setbit_returns_original_bit() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    assert!(!client.setbit("foo", 3, true)?);
+    assert!(client.setbit("foo", 3, true)?);
+    assert!(client.getbit("foo", 3)?);
+    assert!(!client.getbit("foo", 4)?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn bitcount_reflects_set_bits() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.setbit("foo", 0, true)?;
+    client.setbit("foo", 5, true)?;
+    client.setbit("foo", 9, true)?;
+
+    assert_eq!(3, client.bitcount("foo", None)?);
+    assert_eq!(2, client.bitcount("foo", Some((0, 0)))?);
+    assert_eq!(1, client.bitcount("foo", Some((-1, -1)))?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn object_encoding_with_integer_value_returns_int() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "42", Default::default())?;
+
+    assert_eq!("int", client.object_encoding("foo")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn time_is_close_to_local_clock() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?;
+
+    let (seconds, microseconds) = client.time()?;
+
+    assert!(seconds.abs_diff(now.as_secs()) < 60);
+    assert!(microseconds < 1_000_000);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn lastsave_returns_plausible_timestamp() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?;
+
+    let result = client.lastsave()?;
+
+    assert!(result > 0);
+    assert!(result as u64 <= now.as_secs() + 60);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn set_with_nx_and_get_options_on_existing_key_does_not_write() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    let options = SetOptions {
+        set_mode: Some(SetMode::SetIfNotExists),
+        get_previous_value: true,
+        ..Default::default()
+    };
+
+    let result = client.set("foo", "baz", options)?;
+
+    let expected = SetResponse::ConditionalPreviousValue {
+        previous_value: Some(DataType::String("bar".into())),
+        written: false,
+    };
+
+    assert_eq!(expected, result);
+    assert_eq!(Some(DataType::String("bar".into())), client.get("foo")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn set_with_zero_expiration_time_fails_before_sending() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let options = SetOptions {
+        expiration_time: Some(ExpirationTime::Seconds(0)),
+        ..Default::default()
+    };
+
+    let result = client.set("foo", "bar", options);
+
+    assert!(matches!(result, Err(CamasError::InvalidSetOptions(_))));
+    assert_eq!(None, client.get("foo")?);
+
+    teardown(client)
+}
+
+/// Keeps everything the client sends and receives
+#[derive(Clone, Default)]
+struct RecordingObserver {
+    sent: Arc<Mutex<Vec<u8>>>,
+    received: Arc<Mutex<Vec<u8>>>,
+}
+
+impl Observer for RecordingObserver {
+    fn on_send(&self, bytes: &[u8]) {
+        self.sent.lock().unwrap().extend_from_slice(bytes);
+    }
+
+    fn on_receive(&self, bytes: &[u8]) {
+        self.received.lock().unwrap().extend_from_slice(bytes);
+    }
+}
+
+#[test]
+#[serial]
+fn observer_records_set_round_trip() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let observer = RecordingObserver::default();
+
+    client.set_observer(observer.clone());
+
+    client.set("foo", "bar", Default::default())?;
+
+    assert_eq!(
+        b"*3\r\n$3\r\nSET\r\n$3\r\nfoo\r\n$3\r\nbar\r\n".to_vec(),
+        *observer.sent.lock().unwrap()
+    );
+    assert_eq!(b"+OK\r\n".to_vec(), *observer.received.lock().unwrap());
+
+    teardown(client)
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+#[serial]
+fn metrics_count_executed_commands() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let initial_count = client.metrics().count();
+
+    client.set("foo", "bar", Default::default())?;
+    client.get("foo")?;
+    client.get("baz")?;
+
+    let metrics = client.metrics();
+
+    assert_eq!(initial_count + 3, metrics.count());
+    assert_eq!(Some(1), metrics.command("SET").map(|set| set.count));
+    assert_eq!(Some(2), metrics.command("GET").map(|get| get.count));
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn smismember_returns_membership_in_order() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.sadd("foo", &["bar", "baz"])?;
+
+    let result = client.smismember("foo", &["bar", "baz", "qux"])?;
+
+    assert_eq!(vec![true, true, false], result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn hmget_returns_none_for_missing_fields() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.hset("foo", "bar", "1")?;
+    client.hset("foo", "baz", "2")?;
+
+    let result = client.hmget("foo", &["bar", "qux", "baz"])?;
+
+    assert_eq!(
+        vec![
+            Some(DataType::String(String::from("1"))),
+            None,
+            Some(DataType::String(String::from("2")))
+        ],
+        result
+    );
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn hkeys_returns_field_names() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.hset("foo", "bar", "1")?;
+    client.hset("foo", "baz", "2")?;
+
+    let mut result = client.hkeys("foo")?;
+    result.sort();
+
+    assert_eq!(vec![String::from("bar"), String::from("baz")], result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn hvals_returns_field_values() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.hset("foo", "bar", "1")?;
+    client.hset("foo", "baz", "2")?;
+
+    let mut result = client.hvals("foo")?;
+    result.sort();
+
+    assert_eq!(vec![String::from("1"), String::from("2")], result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn set_operations_combine_members() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.sadd("foo", &["a", "b", "c"])?;
+    client.sadd("bar", &["b", "c", "d"])?;
+
+    let mut intersection = client.sinter(&["foo", "bar"])?;
+    intersection.sort();
+
+    let mut union = client.sunion(&["foo", "bar"])?;
+    union.sort();
+
+    let mut difference = client.sdiff(&["foo", "bar"])?;
+    difference.sort();
+
+    assert_eq!(vec!["b", "c"], intersection);
+    assert_eq!(vec!["a", "b", "c", "d"], union);
+    assert_eq!(vec!["a"], difference);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn blpop_receives_value_pushed_while_blocking() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    // Shorter than the wait, so it must be extended while blocking
+    client.set_read_timeout(Some(Duration::from_millis(100)))?;
+
+    let pusher = thread::spawn(|| -> Result<(), CamasError> {
+        let mut client = Client::connect("localhost:6379")?;
+
+        thread::sleep(Duration::from_millis(300));
+
+        client.rpush("foo", &["bar"])?;
+
+        Ok(())
+    });
+
+    let result = client.blpop(&["foo"], Duration::from_secs(5))?;
+
+    pusher.join().expect("Pusher thread panicked")?;
+
+    assert_eq!(
+        Some((String::from("foo"), DataType::String(String::from("bar")))),
+        result
+    );
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn blpop_returns_binary_values_as_they_are() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.execute_command(&camas::commands::Command::Custom {
+        name: "RPUSH".into(),
+        args: vec![
+            ProtocolDataType::BulkString("foo".into()),
+            ProtocolDataType::BulkBytes(vec![0xff, 0x00]),
+        ],
+    })?;
+
+    let result = client.blpop(&["foo"], Duration::from_secs(1))?;
+
+    assert_eq!(
+        Some((String::from("foo"), DataType::Bytes(vec![0xff, 0x00]))),
+        result
+    );
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn brpop_with_empty_lists_returns_none_after_timeout() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.brpop(&["foo", "bar"], Duration::from_millis(100))?;
+
+    assert_eq!(None, result);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn command_count_is_positive() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.command_count()?;
+
+    assert!(result > 0);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn getset_returns_previous_value_and_sets_new_one() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    assert_eq!(None, client.getset("foo", "bar")?);
+
+    let result = client.getset("foo", "baz")?;
+
+    assert_eq!(Some(DataType::String("bar".into())), result);
+    assert_eq!(Some(DataType::String("baz".into())), client.get("foo")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn setex_sets_value_with_ttl() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.setex("foo", 100, "bar")?;
+
+    assert_eq!(Some(DataType::String("bar".into())), client.get("foo")?);
+    assert!(matches!(client.ttl("foo")?, TtlResult::Expires(seconds) if seconds <= 100));
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn psetex_sets_value_with_ttl() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.psetex("foo", 100_000, "bar")?;
+
+    assert_eq!(Some(DataType::String("bar".into())), client.get("foo")?);
+    assert!(
+        matches!(client.pttl("foo")?, TtlResult::Expires(milliseconds) if milliseconds <= 100_000)
+    );
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn setnx_only_sets_fresh_key() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    assert!(client.setnx("foo", "bar")?);
+    assert!(!client.setnx("foo", "baz")?);
+    assert_eq!(Some(DataType::String("bar".into())), client.get("foo")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn move_key_relocates_key_to_other_database() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.select(1)?;
+    client.flushdb(false)?;
+    client.select(0)?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    assert!(client.move_key("foo", 1)?);
+    assert_eq!(None, client.get("foo")?);
+
+    client.select(1)?;
+
+    assert_eq!(Some(DataType::String("bar".into())), client.get("foo")?);
+
+    client.flushdb(false)?;
+    client.select(0)?;
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn restore_with_dumped_value_copies_it_under_new_key() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    let serialized = client.dump("foo")?.ok_or("DUMP returned no value")?;
+
+    client.restore("baz", 0, &serialized, false)?;
+
+    assert_eq!(Some(DataType::String("bar".into())), client.get("baz")?);
+    assert_eq!(None, client.dump("qux")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn get_raw_returns_unconverted_reply() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", 42, Default::default())?;
+
+    assert_eq!(
+        ProtocolDataType::BulkString("42".into()),
+        client.get_raw("foo")?
+    );
+    assert_eq!(ProtocolDataType::Null, client.get_raw("bar")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn is_healthy_with_live_server_returns_true() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    assert!(client.is_healthy());
+
+    teardown(client)
+}
+
+#[test]
+fn is_healthy_with_dropped_connection_returns_false() -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+
+    let mut client = Client::connect(listener.local_addr()?)?;
+
+    drop(listener.accept()?);
+
+    assert!(!client.is_healthy());
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn keepalive_tick_only_pings_after_interval() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    assert!(!client.keepalive_tick()?);
+
+    client.set_keepalive_interval(Some(Duration::from_millis(100)));
+
+    assert!(!client.keepalive_tick()?);
+
+    thread::sleep(Duration::from_millis(150));
+
+    assert!(client.keepalive_tick()?);
+    assert!(!client.keepalive_tick()?);
+
+    teardown(client)
+}
+
+#[test]
+fn keepalive_tick_fails_without_pong() -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+
+    let mut client = Client::connect(listener.local_addr()?)?;
+    client.set_keepalive_interval(Some(Duration::ZERO));
+
+    let server = thread::spawn(move || -> std::io::Result<()> {
+        let (mut stream, _) = listener.accept()?;
+
+        let mut buf = [0u8; 1024];
+
+        while stream.read(&mut buf)? > 0 {
+            stream.write_all(b"$5\r\nHello\r\n")?;
+        }
+
+        Ok(())
+    });
+
+    assert!(matches!(
+        client.keepalive_tick(),
+        Err(CamasError::UnexpectedReply(_))
+    ));
+
+    drop(client);
+
+    server.join().expect("Server thread panicked")?;
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn client_getname_returns_name_set_before() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    assert_eq!(None, client.client_getname()?);
+
+    client.client_setname("worker")?;
+
+    assert_eq!(Some(String::from("worker")), client.client_getname()?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn client_setname_with_space_fails_before_sending() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let result = client.client_setname("two words");
+
+    assert!(matches!(result, Err(CamasError::InvalidArgument(_))));
+    assert_eq!(None, client.client_getname()?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn keyless_commands_are_rejected_before_sending() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let observer = RecordingObserver::default();
+
+    client.set_observer(observer.clone());
+
+    assert!(matches!(
+        client.del(&[] as &[&str]),
+        Err(CamasError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        client.mget(&[] as &[&str]),
+        Err(CamasError::InvalidArgument(_))
+    ));
+    assert!(observer.sent.lock().unwrap().is_empty());
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn send_inline_ping() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    assert_eq!(
+        ProtocolDataType::SimpleString("PONG".into()),
+        client.send_inline("PING")?
+    );
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn send_inline_rejects_line_breaks() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    assert!(matches!(
+        client.send_inline("PING\r\nPING"),
+        Err(CamasError::InvalidArgument(_))
+    ));
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn large_value_round_trips_with_any_buffer_size() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let value = "camas".repeat(20_000);
+
+    for size in [16, 8 * 1024] {
+        client.set_buffer_size(size);
+
+        client.set("buffer-size-key", value.as_str(), Default::default())?;
+
+        assert_eq!(
+            Some(value.clone()),
+            client.get_as::<Option<String>, _>("buffer-size-key")?
+        );
+    }
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn try_clone_opens_independent_connection() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.select(1)?;
+    client.flushdb(false)?;
+
+    let mut clone = client.try_clone()?;
+
+    client.set("foo", "bar", Default::default())?;
+    clone.set("baz", "qux", Default::default())?;
+
+    assert_eq!(Some(DataType::String("qux".into())), client.get("baz")?);
+    assert_eq!(Some(DataType::String("bar".into())), clone.get("foo")?);
+
+    clone.select(0)?;
+
+    assert_eq!(None, clone.get("foo")?);
+    assert_eq!(Some(DataType::String("bar".into())), client.get("foo")?);
+
+    client.flushdb(false)?;
+    client.select(0)?;
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn try_clone_reauthenticates() -> Result<(), Box<dyn Error>> {
+    let client = Client::connect_with_auth("localhost:6380", None, "camas")?;
+
+    let mut clone = client.try_clone()?;
+
+    clone.set("foo", "bar", Default::default())?;
+
+    assert_eq!(1, clone.del(&["foo"])?);
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn pool_shares_connections_between_threads() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("foo", "bar", Default::default())?;
+
+    let pool = Pool::builder()
+        .url("redis://localhost:6379")
+        .size(2)
+        .build()?;
+
+    let workers = (0..8)
+        .map(|_| {
+            let pool = pool.clone();
+
+            thread::spawn(move || pool.get().get("foo"))
+        })
+        .collect::<Vec<_>>();
+
+    for worker in workers {
+        assert_eq!(
+            Some(DataType::String("bar".into())),
+            worker.join().unwrap()?
+        );
+    }
+
+    assert_eq!(2, pool.available());
+
+    teardown(client)
+}
+
+#[test]
+fn pool_get_returns_connection_on_drop() -> Result<(), Box<dyn Error>> {
+    let pool = Pool::builder().size(1).build()?;
+
+    let pooled = pool.get();
+
+    assert!(pool.try_get().is_none());
+
+    drop(pooled);
+
+    assert!(pool.try_get().is_some());
+    assert_eq!(1, pool.available());
+
+    Ok(())
+}
+
+#[test]
+fn pool_rejects_zero_size() {
+    assert!(matches!(
+        Pool::builder().size(0).build(),
+        Err(CamasError::InvalidArgument(_))
+    ));
+}
+
+#[test]
+#[serial]
+fn scan_returns_all_keys() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    for index in 0..25 {
+        client.set(format!("key:{}", index), index, Default::default())?;
+    }
+
+    let mut keys = client
+        .scan(Default::default())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    keys.sort();
+    keys.dedup();
+
+    assert_eq!(25, keys.len());
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn scan_filters_by_type_on_every_round() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    for index in 0..15 {
+        client.set(format!("string:{}", index), index, Default::default())?;
+        client.rpush(format!("list:{}", index), &[index])?;
+    }
+
+    let options = ScanOptions {
+        count: Some(5),
+        type_filter: Some(KeyType::String),
+        ..Default::default()
+    };
+
+    let mut keys = client.scan(options).collect::<Result<Vec<_>, _>>()?;
+
+    keys.sort();
+    keys.dedup();
+
+    assert_eq!(15, keys.len());
+    assert!(keys.iter().all(|key| key.starts_with("string:")));
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn scan_filters_by_pattern() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("user:1", "john", Default::default())?;
+    client.set("user:2", "jane", Default::default())?;
+    client.set("session:1", "abc", Default::default())?;
+
+    let options = ScanOptionsBuilder::default().pattern("user:*").build()?;
+
+    let mut keys = client.scan(options).collect::<Result<Vec<_>, _>>()?;
+
+    keys.sort();
+
+    assert_eq!(vec!["user:1", "user:2"], keys);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn hscan_returns_all_fields() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    for index in 0..50 {
+        client.hset("myhash", format!("field:{}", index), index)?;
+    }
+
+    let mut pairs = client
+        .hscan("myhash", Default::default())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    pairs.sort();
+    pairs.dedup();
+
+    let mut expected = (0..50)
+        .map(|index| (format!("field:{}", index), index.to_string()))
+        .collect::<Vec<_>>();
+
+    expected.sort();
+
+    assert_eq!(expected, pairs);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn sscan_returns_all_members() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let members = (0..50)
+        .map(|index| format!("member:{}", index))
+        .collect::<Vec<_>>();
+
+    client.sadd("myset", &members)?;
+
+    let options = KeyScanOptions {
+        count: Some(7),
+        ..Default::default()
+    };
+
+    let mut scanned = client
+        .sscan("myset", options)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    scanned.sort();
+    scanned.dedup();
+
+    let mut expected = members;
+
+    expected.sort();
+
+    assert_eq!(expected, scanned);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn zscan_returns_all_members_with_scores() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    let pairs = (0..50)
+        .map(|index| (index as f64 + 0.5, format!("member:{}", index)))
+        .collect::<Vec<_>>();
+
+    client.zadd("myzset", &pairs)?;
+
+    let mut scanned = client
+        .zscan("myzset", Default::default())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    scanned.sort_by(|a, b| a.1.total_cmp(&b.1));
+    scanned.dedup();
+
+    let expected = pairs
+        .into_iter()
+        .map(|(score, member)| (member, score))
+        .collect::<Vec<_>>();
+
+    assert_eq!(expected, scanned);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn bitpos_finds_first_set_and_clear_bits() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set_bytes("foo", &[0x00, 0xff, 0xf0], Default::default())?;
+
+    assert_eq!(8, client.bitpos("foo", true, None)?);
+    assert_eq!(0, client.bitpos("foo", false, None)?);
+    assert_eq!(20, client.bitpos("foo", false, Some((1, -1)))?);
+    assert_eq!(16, client.bitpos("foo", true, Some((2, 2)))?);
+    assert_eq!(-1, client.bitpos("foo", true, Some((0, 0)))?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn bitpos_on_missing_key() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    assert_eq!(-1, client.bitpos("foo", true, None)?);
+    assert_eq!(0, client.bitpos("foo", false, None)?);
+
+    teardown(client)
+}
+
+#[test]
+fn empty_string_reply_does_not_corrupt_following_replies() -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+
+    let mut client = Client::connect(listener.local_addr()?)?;
+
+    let server = thread::spawn(move || -> std::io::Result<()> {
+        let (mut stream, _) = listener.accept()?;
+
+        let mut buf = [0u8; 1024];
+
+        for reply in [&b"$0\r\n\r\n"[..], &b"$5\r\nHello\r\n"[..]] {
+            let bytes_read = stream.read(&mut buf)?;
+
+            assert!(bytes_read > 0);
+
+            stream.write_all(reply)?;
+        }
+
+        Ok(())
+    });
+
+    assert_eq!(Some(DataType::String(String::new())), client.get("empty")?);
+    assert_eq!(Some(DataType::String("Hello".into())), client.get("foo")?);
+
+    server.join().unwrap()?;
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn empty_string_round_trips() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.set("empty", "", Default::default())?;
+    client.set("foo", "bar", Default::default())?;
+
+    assert_eq!(Some(DataType::String(String::new())), client.get("empty")?);
+    assert_eq!(Some(DataType::String("bar".into())), client.get("foo")?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn pops_binary_values_as_they_are() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.rpush("foo", &["bar"])?;
+    client.execute_command(&camas::commands::Command::Custom {
+        name: "RPUSH".into(),
+        args: vec![
+            ProtocolDataType::BulkString("foo".into()),
+            ProtocolDataType::BulkBytes(vec![0xff, 0x00]),
+        ],
+    })?;
+
+    assert_eq!(
+        Some(vec![DataType::Bytes(vec![0xff, 0x00])]),
+        client.rpop("foo", None)?
+    );
+    assert_eq!(
+        Some(vec![DataType::String("bar".into())]),
+        client.lpop("foo", None)?
+    );
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn lindex_returns_binary_values_as_they_are() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.execute_command(&camas::commands::Command::Custom {
+        name: "RPUSH".into(),
+        args: vec![
+            ProtocolDataType::BulkString("foo".into()),
+            ProtocolDataType::BulkBytes(vec![0xff, 0x00]),
+        ],
+    })?;
+
+    assert_eq!(
+        Some(DataType::Bytes(vec![0xff, 0x00])),
+        client.lindex("foo", 0)?
+    );
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn binary_values_are_listed_without_quotes() -> Result<(), Box<dyn Error>> {
+    let mut client = setup()?;
+
+    client.execute_command(&camas::commands::Command::Custom {
+        name: "RPUSH".into(),
+        args: vec![
+            ProtocolDataType::BulkString("foo".into()),
+            ProtocolDataType::BulkBytes(vec![0xff, 0x00]),
+            ProtocolDataType::BulkString("bar".into()),
+        ],
+    })?;
+
+    assert_eq!(vec!["\u{fffd}\0", "bar"], client.lrange("foo", 0, -1)?);
+
+    teardown(client)
+}
+
+#[test]
+#[serial]
+fn try_clone_authenticates_before_negotiating_protocol() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::connect_with_auth("localhost:6380", None, "camas")?;
+
+    client.hello(3)?;
+
+    let mut clone = client.try_clone()?;
+
+    clone.set("foo", "bar", Default::default())?;
+
+    assert_eq!(1, clone.del(&["foo"])?);
+
+    Ok(())
+}
@@ -3,7 +3,11 @@ use std::error::Error;
 use camas::client::Client;
 
 pub fn setup() -> Result<Client, Box<dyn Error>> {
-    Ok(Client::connect("localhost:6379")?)
+    let mut client = Client::connect("localhost:6379")?;
+
+    client.flushdb(false)?;
+
+    Ok(client)
 }
 
 pub fn teardown(mut client: Client) -> Result<(), Box<dyn Error>> {